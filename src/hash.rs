@@ -0,0 +1,162 @@
+use core::fmt::Debug;
+use crate::sha256::{Sha224, Sha256};
+use crate::sha512::{Sha384, Sha512, Sha512_224, Sha512_256};
+
+/// A streaming hash function, as used by HMAC and `merkle_tree_with`.
+///
+/// # Description
+/// - `BLOCK_SIZE`: Input block size in bytes (64 for SHA-224/256, 128 for 
+///   the SHA-512 family); HMAC normalizes keys to this length.
+/// - `OUTPUT_SIZE`: Digest size in bytes.
+/// - `new`, `update` and `finalize` hash a message incrementally; `digest`
+///   hashes it in one call.
+pub trait HashFunction: Clone {
+    const BLOCK_SIZE: usize;
+    const OUTPUT_SIZE: usize;
+    type Output: Copy + AsRef<[u8]> + PartialEq + Debug;
+
+    fn new() -> Self;
+    fn update(&mut self, msg: &[u8]);
+    fn finalize(self) -> Self::Output;
+
+    /// Hash `msg` in one call.
+    fn digest(msg: &[u8]) -> Self::Output {
+        let mut hasher = Self::new();
+        hasher.update(msg);
+        hasher.finalize()
+    }
+}
+
+impl HashFunction for Sha224 {
+    const BLOCK_SIZE: usize = 64;
+    const OUTPUT_SIZE: usize = 28;
+    type Output = [u8; 28];
+
+    fn new() -> Self {
+        Sha224::new()
+    }
+
+    fn update(&mut self, msg: &[u8]) {
+        Sha224::update(self, msg);
+    }
+
+    fn finalize(self) -> [u8; 28] {
+        Sha224::finalize(self)
+    }
+}
+
+impl HashFunction for Sha256 {
+    const BLOCK_SIZE: usize = 64;
+    const OUTPUT_SIZE: usize = 32;
+    type Output = [u8; 32];
+
+    fn new() -> Self {
+        Sha256::new()
+    }
+
+    fn update(&mut self, msg: &[u8]) {
+        Sha256::update(self, msg);
+    }
+
+    fn finalize(self) -> [u8; 32] {
+        Sha256::finalize(self)
+    }
+}
+
+impl HashFunction for Sha384 {
+    const BLOCK_SIZE: usize = 128;
+    const OUTPUT_SIZE: usize = 48;
+    type Output = [u8; 48];
+
+    fn new() -> Self {
+        Sha384::new()
+    }
+
+    fn update(&mut self, msg: &[u8]) {
+        Sha384::update(self, msg);
+    }
+
+    fn finalize(self) -> [u8; 48] {
+        Sha384::finalize(self)
+    }
+}
+
+impl HashFunction for Sha512 {
+    const BLOCK_SIZE: usize = 128;
+    const OUTPUT_SIZE: usize = 64;
+    type Output = [u8; 64];
+
+    fn new() -> Self {
+        Sha512::new()
+    }
+
+    fn update(&mut self, msg: &[u8]) {
+        Sha512::update(self, msg);
+    }
+
+    fn finalize(self) -> [u8; 64] {
+        Sha512::finalize(self)
+    }
+}
+
+impl HashFunction for Sha512_224 {
+    const BLOCK_SIZE: usize = 128;
+    const OUTPUT_SIZE: usize = 28;
+    type Output = [u8; 28];
+
+    fn new() -> Self {
+        Sha512_224::new()
+    }
+
+    fn update(&mut self, msg: &[u8]) {
+        Sha512_224::update(self, msg);
+    }
+
+    fn finalize(self) -> [u8; 28] {
+        Sha512_224::finalize(self)
+    }
+}
+
+impl HashFunction for Sha512_256 {
+    const BLOCK_SIZE: usize = 128;
+    const OUTPUT_SIZE: usize = 32;
+    type Output = [u8; 32];
+
+    fn new() -> Self {
+        Sha512_256::new()
+    }
+
+    fn update(&mut self, msg: &[u8]) {
+        Sha512_256::update(self, msg);
+    }
+
+    fn finalize(self) -> [u8; 32] {
+        Sha512_256::finalize(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::sha256::{sha224, sha256};
+    use crate::sha512::{sha384, sha512, sha512_224, sha512_256};
+
+    #[test]
+    fn digest_matches_one_shot_functions() {
+        let msg = b"The quick brown fox jumps over the lazy dog";
+
+        assert_eq!((Sha224::digest(msg)), (sha224(msg)));
+        assert_eq!((Sha256::digest(msg)), (sha256(msg)));
+        assert_eq!((Sha384::digest(msg)), (sha384(msg)));
+        assert_eq!((Sha512::digest(msg)), (sha512(msg)));
+        assert_eq!((Sha512_224::digest(msg)), (sha512_224(msg)));
+        assert_eq!((Sha512_256::digest(msg)), (sha512_256(msg)));
+    }
+
+    #[test]
+    fn output_size_matches_digest_length() {
+        assert_eq!((Sha256::digest(b"").as_ref().len()), (Sha256::OUTPUT_SIZE));
+        assert_eq!((Sha384::digest(b"").as_ref().len()), (Sha384::OUTPUT_SIZE));
+        assert_eq!((Sha512::digest(b"").as_ref().len()), (Sha512::OUTPUT_SIZE));
+    }
+}
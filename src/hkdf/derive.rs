@@ -0,0 +1,102 @@
+use crate::hkdf::{expand, extract, HkdfError};
+
+/// HKDF (extract-then-expand) with HMAC-SHA-256 in one call.
+///
+/// # Arguments
+/// - `salt`: Optional salt (empty means "not provided").
+/// - `ikm`: Input keying material.
+/// - `info`: Context and application specific information.
+/// - `len`: Length of the output keying material in bytes.
+///
+/// # Returns
+/// `expand(&extract(salt, ikm), info, len)`.
+///
+/// # Reference
+/// [RFC 5869](https://www.rfc-editor.org/rfc/rfc5869)
+pub fn derive(
+    salt: &[u8], 
+    ikm: &[u8], 
+    info: &[u8], 
+    len: usize,
+) -> Result<Vec<u8>, HkdfError> {
+    let prk = extract(salt, ikm);
+    expand(&prk, info, len)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn rfc5869_case1_basic() {
+        let salt: Vec<u8> = (0x00..=0x0c).collect();
+        let info: Vec<u8> = (0xf0..=0xf9).collect();
+
+        let result = derive(&salt, &[0x0b; 22], &info, 42).unwrap();
+        let expected = [
+            0x3c, 0xb2, 0x5f, 0x25, 0xfa, 0xac, 0xd5, 0x7a,
+            0x90, 0x43, 0x4f, 0x64, 0xd0, 0x36, 0x2f, 0x2a,
+            0x2d, 0x2d, 0x0a, 0x90, 0xcf, 0x1a, 0x5a, 0x4c,
+            0x5d, 0xb0, 0x2d, 0x56, 0xec, 0xc4, 0xc5, 0xbf,
+            0x34, 0x00, 0x72, 0x08, 0xd5, 0xb8, 0x87, 0x18,
+            0x58, 0x65,
+        ];
+
+        assert_eq!((result), (expected));
+    }
+
+    #[test]
+    fn rfc5869_case2_longer_inputs_and_outputs() {
+        let ikm: Vec<u8> = (0x00..=0x4f).collect();
+        let salt: Vec<u8> = (0x60..=0xaf).collect();
+        let info: Vec<u8> = (0xb0..=0xff).collect();
+
+        let prk = extract(&salt, &ikm);
+        let expected_prk = [
+            0x06, 0xa6, 0xb8, 0x8c, 0x58, 0x53, 0x36, 0x1a,
+            0x06, 0x10, 0x4c, 0x9c, 0xeb, 0x35, 0xb4, 0x5c,
+            0xef, 0x76, 0x00, 0x14, 0x90, 0x46, 0x71, 0x01,
+            0x4a, 0x19, 0x3f, 0x40, 0xc1, 0x5f, 0xc2, 0x44,
+        ];
+        assert_eq!((prk.as_bytes()), (&expected_prk));
+
+        let result = derive(&salt, &ikm, &info, 82).unwrap();
+        let expected = [
+            0xb1, 0x1e, 0x39, 0x8d, 0xc8, 0x03, 0x27, 0xa1,
+            0xc8, 0xe7, 0xf7, 0x8c, 0x59, 0x6a, 0x49, 0x34,
+            0x4f, 0x01, 0x2e, 0xda, 0x2d, 0x4e, 0xfa, 0xd8,
+            0xa0, 0x50, 0xcc, 0x4c, 0x19, 0xaf, 0xa9, 0x7c,
+            0x59, 0x04, 0x5a, 0x99, 0xca, 0xc7, 0x82, 0x72,
+            0x71, 0xcb, 0x41, 0xc6, 0x5e, 0x59, 0x0e, 0x09,
+            0xda, 0x32, 0x75, 0x60, 0x0c, 0x2f, 0x09, 0xb8,
+            0x36, 0x77, 0x93, 0xa9, 0xac, 0xa3, 0xdb, 0x71,
+            0xcc, 0x30, 0xc5, 0x81, 0x79, 0xec, 0x3e, 0x87,
+            0xc1, 0x4c, 0x01, 0xd5, 0xc1, 0xf3, 0x43, 0x4f,
+            0x1d, 0x87,
+        ];
+
+        assert_eq!((result), (expected));
+    }
+
+    #[test]
+    fn rfc5869_case3_zero_length_salt_and_info() {
+        let result = derive(b"", &[0x0b; 22], b"", 42).unwrap();
+        let expected = [
+            0x8d, 0xa4, 0xe7, 0x75, 0xa5, 0x63, 0xc1, 0x8f,
+            0x71, 0x5f, 0x80, 0x2a, 0x06, 0x3c, 0x5a, 0x31,
+            0xb8, 0xa1, 0x1f, 0x5c, 0x5e, 0xe1, 0x87, 0x9e,
+            0xc3, 0x45, 0x4e, 0x5f, 0x3c, 0x73, 0x8d, 0x2d,
+            0x9d, 0x20, 0x13, 0x95, 0xfa, 0xa4, 0xb6, 0x1a,
+            0x96, 0xc8,
+        ];
+
+        assert_eq!((result), (expected));
+    }
+
+    #[test]
+    fn derive_propagates_length_error() {
+        let result = derive(b"salt", b"ikm", b"", 255 * 32 + 1);
+
+        assert_eq!((result), (Err(HkdfError::OutputTooLong)));
+    }
+}
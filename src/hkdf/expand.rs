@@ -0,0 +1,131 @@
+use core::fmt;
+use crate::hkdf::{Prk, HASH_LEN};
+use crate::hmac::HmacSha256;
+
+/// Maximum output length of HKDF-Expand: 255 × HashLen bytes.
+pub const MAX_OUTPUT_LEN: usize = 255 * HASH_LEN;
+
+/// Reasons HKDF-Expand refuses a request.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HkdfError {
+    /// The requested length exceeds `MAX_OUTPUT_LEN`.
+    OutputTooLong,
+}
+
+impl fmt::Display for HkdfError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HkdfError::OutputTooLong => {
+                write!(f, "output length exceeds 255 * HashLen bytes")
+            }
+        }
+    }
+}
+
+impl std::error::Error for HkdfError {}
+
+/// HKDF-Expand with HMAC-SHA-256.
+///
+/// # Arguments
+/// - `prk`: Pseudorandom key from `extract`.
+/// - `info`: Context and application specific information (may be empty).
+/// - `len`: Length of the output keying material in bytes.
+///
+/// # Description
+/// - Key HMAC once with the PRK and reuse the keyed state for every block.
+/// - T(0) = empty string; T(i) = HMAC-Hash(PRK, T(i-1) || info || i).
+/// - OKM = first `len` bytes of T(1) || T(2) || ... 
+///
+/// # Returns
+/// The output keying material, or `HkdfError::OutputTooLong` when `len` is
+/// above 255 × HashLen.
+///
+/// # Reference
+/// [RFC 5869](https://www.rfc-editor.org/rfc/rfc5869)
+pub fn expand(prk: &Prk, info: &[u8], len: usize) -> Result<Vec<u8>, HkdfError> {
+    if len > MAX_OUTPUT_LEN {
+        return Err(HkdfError::OutputTooLong);
+    }
+
+    let keyed = HmacSha256::new(prk.as_bytes());
+    let mut okm: Vec<u8> = Vec::with_capacity(len);
+    let mut t = [0u8; HASH_LEN];
+    let mut counter: u8 = 1;
+
+    while okm.len() < len {
+        let mut mac = keyed.clone();
+        // T(0) is empty, later blocks chain the previous output.
+        if counter > 1 {
+            mac.update(&t);
+        }
+        mac.update(info);
+        mac.update(&[counter]);
+        t = mac.finalize();
+
+        let mut i = 0;
+        while i < HASH_LEN && okm.len() < len {
+            okm.push(t[i]);
+            i += 1;
+        }
+        counter = counter.wrapping_add(1);
+    }
+
+    Ok(okm)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn rfc5869_case1_expand_from_prk() {
+        let prk = Prk::from_bytes([
+            0x07, 0x77, 0x09, 0x36, 0x2c, 0x2e, 0x32, 0xdf,
+            0x0d, 0xdc, 0x3f, 0x0d, 0xc4, 0x7b, 0xba, 0x63,
+            0x90, 0xb6, 0xc7, 0x3b, 0xb5, 0x0f, 0x9c, 0x31,
+            0x22, 0xec, 0x84, 0x4a, 0xd7, 0xc2, 0xb3, 0xe5,
+        ]);
+        let info: Vec<u8> = (0xf0..=0xf9).collect();
+
+        let result = expand(&prk, &info, 42).unwrap();
+        let expected = [
+            0x3c, 0xb2, 0x5f, 0x25, 0xfa, 0xac, 0xd5, 0x7a,
+            0x90, 0x43, 0x4f, 0x64, 0xd0, 0x36, 0x2f, 0x2a,
+            0x2d, 0x2d, 0x0a, 0x90, 0xcf, 0x1a, 0x5a, 0x4c,
+            0x5d, 0xb0, 0x2d, 0x56, 0xec, 0xc4, 0xc5, 0xbf,
+            0x34, 0x00, 0x72, 0x08, 0xd5, 0xb8, 0x87, 0x18,
+            0x58, 0x65,
+        ];
+
+        assert_eq!((result), (expected));
+    }
+
+    #[test]
+    fn shorter_output_is_prefix_of_longer_output() {
+        let prk = Prk::from_bytes([0x42; HASH_LEN]);
+
+        let long = expand(&prk, b"info", 100).unwrap();
+        let short = expand(&prk, b"info", 33).unwrap();
+
+        assert_eq!((short), (long[..33].to_vec()));
+        assert_eq!((expand(&prk, b"info", 0).unwrap()), (Vec::<u8>::new()));
+    }
+
+    #[test]
+    fn accepts_maximum_length() {
+        let prk = Prk::from_bytes([0x42; HASH_LEN]);
+
+        let result = expand(&prk, b"", MAX_OUTPUT_LEN).unwrap();
+
+        assert_eq!((result.len()), (8160));
+    }
+
+    #[test]
+    fn rejects_length_above_255_hash_len() {
+        let prk = Prk::from_bytes([0x42; HASH_LEN]);
+
+        let result = expand(&prk, b"", MAX_OUTPUT_LEN + 1);
+
+        assert_eq!((result), (Err(HkdfError::OutputTooLong)));
+    }
+}
@@ -0,0 +1,78 @@
+use crate::hmac::hmac_sha256;
+
+/// Length of the SHA-256 output (`HashLen`) in bytes.
+pub const HASH_LEN: usize = 32;
+
+/// Pseudorandom key produced by `extract` and consumed by `expand`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Prk([u8; HASH_LEN]);
+
+impl Prk {
+    /// Wrap an existing pseudorandom key, e.g. one that was stored.
+    pub fn from_bytes(bytes: [u8; HASH_LEN]) -> Self {
+        Prk(bytes)
+    }
+
+    /// Raw bytes of the pseudorandom key.
+    pub fn as_bytes(&self) -> &[u8; HASH_LEN] {
+        &self.0
+    }
+}
+
+/// HKDF-Extract with HMAC-SHA-256.
+///
+/// # Arguments
+/// - `salt`: Optional salt as a byte slice (`&[u8]`); empty means "not
+///   provided".
+/// - `ikm`: Input keying material as a byte slice (`&[u8]`).
+///
+/// # Description
+/// - If no salt is provided, use a string of `HashLen` zeros (RFC 5869, 
+///   section 2.2).
+/// - PRK = HMAC-Hash(salt, IKM).
+///
+/// # Returns
+/// The pseudorandom key (`Prk`).
+///
+/// # Reference
+/// [RFC 5869](https://www.rfc-editor.org/rfc/rfc5869)
+pub fn extract(salt: &[u8], ikm: &[u8]) -> Prk {
+    let zeros = [0u8; HASH_LEN];
+    let salt = if salt.is_empty() { &zeros[..] } else { salt };
+
+    Prk(hmac_sha256(salt, ikm))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn rfc5869_case1_extract() {
+        let salt: Vec<u8> = (0x00..=0x0c).collect();
+
+        let result = extract(&salt, &[0x0b; 22]);
+        let expected = [
+            0x07, 0x77, 0x09, 0x36, 0x2c, 0x2e, 0x32, 0xdf,
+            0x0d, 0xdc, 0x3f, 0x0d, 0xc4, 0x7b, 0xba, 0x63,
+            0x90, 0xb6, 0xc7, 0x3b, 0xb5, 0x0f, 0x9c, 0x31,
+            0x22, 0xec, 0x84, 0x4a, 0xd7, 0xc2, 0xb3, 0xe5,
+        ];
+
+        assert_eq!((result.as_bytes()), (&expected));
+    }
+
+    #[test]
+    fn rfc5869_case3_empty_salt_is_hash_len_zeros() {
+        let result = extract(b"", &[0x0b; 22]);
+        let expected = [
+            0x19, 0xef, 0x24, 0xa3, 0x2c, 0x71, 0x7b, 0x16,
+            0x7f, 0x33, 0xa9, 0x1d, 0x6f, 0x64, 0x8b, 0xdf,
+            0x96, 0x59, 0x67, 0x76, 0xaf, 0xdb, 0x63, 0x77,
+            0xac, 0x43, 0x4c, 0x1c, 0x29, 0x3c, 0xcb, 0x04,
+        ];
+
+        assert_eq!((result.as_bytes()), (&expected));
+        assert!(result == extract(&[0u8; HASH_LEN], &[0x0b; 22]));
+    }
+}
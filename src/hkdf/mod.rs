@@ -0,0 +1,7 @@
+pub mod derive;
+pub mod expand;
+pub mod extract;
+
+pub use derive::derive;
+pub use expand::{expand, HkdfError, MAX_OUTPUT_LEN};
+pub use extract::{extract, Prk, HASH_LEN};
\ No newline at end of file
@@ -1,10 +1,12 @@
-use crate::sha256::sha256;
+use crate::hash::HashFunction;
 use crate::hmac::get_right_block_size;
+use crate::sha256::Sha256;
 
 /// HMAC (Hash-based Message Authentication Code) using SHA-256.
 /// 
 /// # Arguments
-/// - `k`: Key as a byte slice (`&[u8]`).  
+/// - `k`: Key as a byte slice (`&[u8]`), already normalized to 64 bytes 
+///   (`K0`, see `get_right_block_size`).  
 /// - `m`: Message as a byte slice (`&[u8]`).  
 ///
 /// # Description
@@ -20,42 +22,69 @@ use crate::hmac::get_right_block_size;
 /// - [RFC 2104](https://www.rfc-editor.org/rfc/rfc2104)  
 /// - [RFC 4231](https://www.rfc-editor.org/rfc/rfc4231)
 pub fn hmac(k: &[u8], m: &[u8]) -> [u8; 32] {
-    let mut result: Vec<u8> = Vec::new();
-    let mut ipad: Vec<u8> = vec![0x36u8; 64];
-    let mut opad: Vec<u8> = vec![0x5cu8; 64];
+    hmac_with::<Sha256>(k, m)
+}
+
+/// HMAC-SHA-256 taking a raw key of any length.
+///
+/// # Arguments
+/// - `key`: Key as a byte slice (`&[u8]`), empty, short or longer than the 
+///   block size.  
+/// - `msg`: Message as a byte slice (`&[u8]`).  
+///
+/// # Description
+/// - Normalize the key to `K0` (RFC 2104, section 2) with 
+///   `get_right_block_size`.
+/// - Compute `hmac` with the normalized key.
+///
+/// # Returns
+/// A 32-byte array containing the authentication tag (MAC).
+pub fn hmac_sha256(key: &[u8], msg: &[u8]) -> [u8; 32] {
+    let k0 = get_right_block_size(key);
+    hmac(&k0, msg)
+}
+
+/// HMAC over any `HashFunction` `H`.
+///
+/// # Arguments
+/// - `k`: Key as a byte slice (`&[u8]`), already normalized to 
+///   `H::BLOCK_SIZE` bytes (`K0`, see `get_right_block_size_with`).  
+/// - `m`: Message as a byte slice (`&[u8]`).  
+///
+/// # Description
+/// Same construction as `hmac`, with the pads sized to the block size of `H`.
+///
+/// # Returns
+/// The authentication tag as `H::Output`.
+pub fn hmac_with<H: HashFunction>(k: &[u8], m: &[u8]) -> H::Output {
+    let block_size = H::BLOCK_SIZE;
+    let mut ipad: Vec<u8> = vec![0x36u8; block_size];
+    let mut opad: Vec<u8> = vec![0x5cu8; block_size];
 
     // Vector ipad[i] and opad[i] XORed with k[i].
-    for i in 0..64 {
+    for i in 0..block_size {
         ipad[i] ^= k[i];
         opad[i] ^= k[i];
     };
 
-    let msg: Vec<u8> = m.to_vec();
-    let msg_len = m.len();
-
-    // ((K0 ^ ipad) || text)).
-    let mut j = 0;
-    while j < msg_len {
-        ipad.push(msg[j]);
-        j += 1;
-    };
-
     // H((K0 ^ ipad) || text)).
-    let sha = sha256(&ipad);
+    let mut inner = H::new();
+    inner.update(&ipad);
+    inner.update(m);
+    let sha = inner.finalize();
 
-    // ((K0 ^ opad )|| H((K0 ^ ipad) || text))
-    for k in 0..32 {
-        opad.push(sha[k]);
-    };
-    
-    // h((K0 ^ opad )|| H((K0 ^ ipad) || text)).
-    sha256(&opad)
+    // H((K0 ^ opad )|| H((K0 ^ ipad) || text)).
+    let mut outer = H::new();
+    outer.update(&opad);
+    outer.update(sha.as_ref());
+    outer.finalize()
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::hmac::get_right_block_size;
+    use crate::hmac::get_right_block_size_with;
+    use crate::sha512::{Sha384, Sha512};
 
     #[test]
     fn computes_the_right_vector() {
@@ -126,4 +155,146 @@ mod test {
         ];
         assert_eq!(mac, expected);
     }
+
+    #[test]
+    fn rfc4231_case1_hmac_sha512() {
+        let k = get_right_block_size_with::<Sha512>(&[0x0b; 20]);
+        let mac = hmac_with::<Sha512>(&k, b"Hi There");
+        let expected = [
+            0x87, 0xaa, 0x7c, 0xde, 0xa5, 0xef, 0x61, 0x9d,
+            0x4f, 0xf0, 0xb4, 0x24, 0x1a, 0x1d, 0x6c, 0xb0,
+            0x23, 0x79, 0xf4, 0xe2, 0xce, 0x4e, 0xc2, 0x78,
+            0x7a, 0xd0, 0xb3, 0x05, 0x45, 0xe1, 0x7c, 0xde,
+            0xda, 0xa8, 0x33, 0xb7, 0xd6, 0xb8, 0xa7, 0x02,
+            0x03, 0x8b, 0x27, 0x4e, 0xae, 0xa3, 0xf4, 0xe4,
+            0xbe, 0x9d, 0x91, 0x4e, 0xeb, 0x61, 0xf1, 0x70,
+            0x2e, 0x69, 0x6c, 0x20, 0x3a, 0x12, 0x68, 0x54,
+        ];
+        assert_eq!(mac, expected);
+    }
+
+    #[test]
+    fn rfc4231_case6_hmac_sha384_long_key() {
+        let long_key = vec![0xaa; 131];
+        let k = get_right_block_size_with::<Sha384>(&long_key);
+        let msg = b"Test Using Larger Than Block-Size Key - Hash Key First";
+        let mac = hmac_with::<Sha384>(&k, msg);
+        let expected = [
+            0x4e, 0xce, 0x08, 0x44, 0x85, 0x81, 0x3e, 0x90,
+            0x88, 0xd2, 0xc6, 0x3a, 0x04, 0x1b, 0xc5, 0xb4,
+            0x4f, 0x9e, 0xf1, 0x01, 0x2a, 0x2b, 0x58, 0x8f,
+            0x3c, 0xd1, 0x1f, 0x05, 0x03, 0x3a, 0xc4, 0xc6,
+            0x0c, 0x2e, 0xf6, 0xab, 0x40, 0x30, 0xfe, 0x82,
+            0x96, 0x24, 0x8d, 0xf1, 0x63, 0xf4, 0x49, 0x52,
+        ];
+        assert_eq!(mac, expected);
+    }
+
+    #[test]
+    fn hmac_sha256_brown_fox_with_raw_short_key() {
+        let msg = b"The quick brown fox jumps over the lazy dog"; 
+
+        let result = hmac_sha256(b"key", msg);
+        let expected = hmac(&get_right_block_size(b"key"), msg);
+
+        assert_eq!((result), (expected));
+    }
+
+    #[test]
+    fn hmac_sha256_rfc4231_case1() {
+        let mac = hmac_sha256(&[0x0b; 20], b"Hi There");
+        let expected = [
+            0xb0, 0x34, 0x4c, 0x61, 0xd8, 0xdb, 0x38, 0x53,
+            0x5c, 0xa8, 0xaf, 0xce, 0xaf, 0x0b, 0xf1, 0x2b,
+            0x88, 0x1d, 0xc2, 0x00, 0xc9, 0x83, 0x3d, 0xa7,
+            0x26, 0xe9, 0x37, 0x6c, 0x2e, 0x32, 0xcf, 0xf7,
+        ];
+        assert_eq!(mac, expected);
+    }
+
+    #[test]
+    fn hmac_sha256_rfc4231_case2_key_shorter_than_output() {
+        let mac = hmac_sha256(b"Jefe", b"what do ya want for nothing?");
+        let expected = [
+            0x5b, 0xdc, 0xc1, 0x46, 0xbf, 0x60, 0x75, 0x4e,
+            0x6a, 0x04, 0x24, 0x26, 0x08, 0x95, 0x75, 0xc7,
+            0x5a, 0x00, 0x3f, 0x08, 0x9d, 0x27, 0x39, 0x83,
+            0x9d, 0xec, 0x58, 0xb9, 0x64, 0xec, 0x38, 0x43,
+        ];
+        assert_eq!(mac, expected);
+    }
+
+    #[test]
+    fn hmac_sha256_rfc4231_case3() {
+        let mac = hmac_sha256(&[0xaa; 20], &[0xdd; 50]);
+        let expected = [
+            0x77, 0x3e, 0xa9, 0x1e, 0x36, 0x80, 0x0e, 0x46,
+            0x85, 0x4d, 0xb8, 0xeb, 0xd0, 0x91, 0x81, 0xa7,
+            0x29, 0x59, 0x09, 0x8b, 0x3e, 0xf8, 0xc1, 0x22,
+            0xd9, 0x63, 0x55, 0x14, 0xce, 0xd5, 0x65, 0xfe,
+        ];
+        assert_eq!(mac, expected);
+    }
+
+    #[test]
+    fn hmac_sha256_rfc4231_case4() {
+        let key: Vec<u8> = (0x01..=0x19).collect();
+        let mac = hmac_sha256(&key, &[0xcd; 50]);
+        let expected = [
+            0x82, 0x55, 0x8a, 0x38, 0x9a, 0x44, 0x3c, 0x0e,
+            0xa4, 0xcc, 0x81, 0x98, 0x99, 0xf2, 0x08, 0x3a,
+            0x85, 0xf0, 0xfa, 0xa3, 0xe5, 0x78, 0xf8, 0x07,
+            0x7a, 0x2e, 0x3f, 0xf4, 0x67, 0x29, 0x66, 0x5b,
+        ];
+        assert_eq!(mac, expected);
+    }
+
+    #[test]
+    fn hmac_sha256_long_key_vector_without_pre_normalization() {
+        let long_key: Vec<u8> = vec![0xaa; 131];
+        let msg = b"Test Using Larger Than Block-Size Key - Hash Key First";
+
+        let result = hmac_sha256(&long_key, msg);
+        let expected = hmac(&get_right_block_size(&long_key), msg);
+
+        assert_eq!((result), (expected));
+    }
+
+    #[test]
+    fn hmac_sha256_long_key_long_msg() {
+        let long_key = vec![0xaa; 131];
+        let msg = b"This is a test using a larger than block-size key and a \
+        larger than block-size data. The key needs to be hashed before being \
+        used by the HMAC algorithm.";
+        let mac = hmac_sha256(&long_key, msg);
+        let expected = [
+            0x9b, 0x09, 0xff, 0xa7, 0x1b, 0x94, 0x2f, 0xcb,
+            0x27, 0x63, 0x5f, 0xbc, 0xd5, 0xb0, 0xe9, 0x44,
+            0xbf, 0xdc, 0x63, 0x64, 0x4f, 0x07, 0x13, 0x93,
+            0x8a, 0x7f, 0x51, 0x53, 0x5c, 0x3a, 0x35, 0xe2,
+        ];
+        assert_eq!(mac, expected);
+    }
+
+    #[test]
+    fn hmac_sha256_accepts_empty_key() {
+        let mac = hmac_sha256(b"", b"");
+        let expected = [
+            0xb6, 0x13, 0x67, 0x9a, 0x08, 0x14, 0xd9, 0xec,
+            0x77, 0x2f, 0x95, 0xd7, 0x78, 0xc3, 0x5f, 0xc5,
+            0xff, 0x16, 0x97, 0xc4, 0x93, 0x71, 0x56, 0x53,
+            0xc6, 0xc7, 0x12, 0x14, 0x42, 0x92, 0xc5, 0xad,
+        ];
+        assert_eq!(mac, expected);
+    }
+
+    #[test]
+    fn hmac_sha256_accepts_keys_around_the_block_size() {
+        for len in [63, 64, 65] {
+            let key = vec![0x5au8; len];
+            let mac = hmac_sha256(&key, b"msg");
+            let expected = hmac(&get_right_block_size(&key), b"msg");
+            assert_eq!(mac, expected);
+        }
+    }
 }
\ No newline at end of file
@@ -1,5 +1,10 @@
+#[allow(clippy::module_inception)]
 pub mod hmac;
 pub mod size_util;
+pub mod streaming;
+pub mod verify;
 
-pub use hmac::hmac;
-pub use size_util::get_right_block_size;
\ No newline at end of file
+pub use hmac::{hmac, hmac_sha256, hmac_with};
+pub use size_util::{get_right_block_size, get_right_block_size_with};
+pub use streaming::{Hmac, HmacSha256};
+pub use verify::{min_tag_len, verify, MacError};
\ No newline at end of file
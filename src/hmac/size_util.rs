@@ -1,4 +1,5 @@
-use crate::sha256::sha256;
+use crate::hash::HashFunction;
+use crate::sha256::Sha256;
 
 /// Normalize a key to the correct block size (64 bytes for SHA-256).
 ///
@@ -14,9 +15,21 @@ use crate::sha256::sha256;
 /// # Returns
 /// A vector of bytes (`Vec<u8>`) representing the normalized key (`K0`).
 pub fn get_right_block_size(k: &[u8]) -> Vec<u8> {
+    get_right_block_size_with::<Sha256>(k)
+}
+
+/// Normalize a key to the block size of any `HashFunction` `H`.
+///
+/// # Description
+/// Same rules as `get_right_block_size`, using `H::BLOCK_SIZE` and hashing 
+/// long keys with `H`.
+///
+/// # Returns
+/// A vector of `H::BLOCK_SIZE` bytes (`Vec<u8>`) representing `K0`.
+pub fn get_right_block_size_with<H: HashFunction>(k: &[u8]) -> Vec<u8> {
     let mut k0: Vec<u8> = k.to_vec();
-    let mut key_length = k0.len();
-    let block_size = 64;
+    let key_length = k0.len();
+    let block_size = H::BLOCK_SIZE;
     
     // k = k0 if key length == block_size.
     if key_length == block_size {
@@ -25,7 +38,7 @@ pub fn get_right_block_size(k: &[u8]) -> Vec<u8> {
     
     // Hash k0 and append 0s if key_length > block_size.
     if key_length > block_size {
-        k0 = sha256(&k0).to_vec();
+        k0 = H::digest(&k0).as_ref().to_vec();
         let mut k_l = k0.len();
         while k_l < block_size {
             k0.push(0x00);
@@ -48,6 +61,7 @@ pub fn get_right_block_size(k: &[u8]) -> Vec<u8> {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::sha512::{sha512, Sha512};
 
     #[test]
     fn computes_k_as_k0_since_k_length_is_block_length() {
@@ -109,4 +123,15 @@ mod test {
 
         assert_eq!((result), (expected));
     }
+
+    #[test]
+    fn normalizes_to_128_bytes_for_sha512() {
+        let k = [0xaau8; 131];
+
+        let result = get_right_block_size_with::<Sha512>(&k);
+        let mut expected = sha512(&k).to_vec();
+        expected.extend_from_slice(&[0u8; 64]);
+
+        assert_eq!((result), (expected));
+    }
 }
\ No newline at end of file
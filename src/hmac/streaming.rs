@@ -0,0 +1,144 @@
+use crate::hash::HashFunction;
+use crate::hmac::get_right_block_size_with;
+use crate::sha256::Sha256;
+
+/// Streaming HMAC over any `HashFunction` `H`.
+///
+/// # Description
+/// - `new` normalizes the key to `K0`, then absorbs `K0 ^ ipad` and 
+///   `K0 ^ opad` into two hasher states. Both pads are exactly one block, so
+///   these states hold the compressed midstates and are cached.
+/// - `update` feeds the message into a copy of the inner state.
+/// - `finalize` hashes the inner digest on a copy of the outer state.
+/// - `reset` drops the absorbed message and restarts from the cached inner 
+///   state, so repeated MACs under the same key skip both pad compressions.
+///
+/// Cloning a freshly keyed `Hmac` is the cheapest way to MAC many messages 
+/// under one key.
+///
+/// # References
+/// - [RFC 2104](https://www.rfc-editor.org/rfc/rfc2104)  
+/// - [RFC 4231](https://www.rfc-editor.org/rfc/rfc4231)
+#[derive(Clone)]
+pub struct Hmac<H: HashFunction> {
+    inner_init: H,
+    outer_init: H,
+    inner: H,
+}
+
+/// Streaming HMAC-SHA-256.
+pub type HmacSha256 = Hmac<Sha256>;
+
+impl<H: HashFunction> Hmac<H> {
+    /// Key a new MAC state; `key` may be of any length.
+    pub fn new(key: &[u8]) -> Self {
+        let k0 = get_right_block_size_with::<H>(key);
+        let block_size = H::BLOCK_SIZE;
+        let mut ipad: Vec<u8> = vec![0x36u8; block_size];
+        let mut opad: Vec<u8> = vec![0x5cu8; block_size];
+
+        // Vector ipad[i] and opad[i] XORed with k0[i].
+        for i in 0..block_size {
+            ipad[i] ^= k0[i];
+            opad[i] ^= k0[i];
+        };
+
+        let mut inner_init = H::new();
+        inner_init.update(&ipad);
+        let mut outer_init = H::new();
+        outer_init.update(&opad);
+
+        Hmac {
+            inner: inner_init.clone(),
+            inner_init,
+            outer_init,
+        }
+    }
+
+    /// Absorb `msg` into the inner hash.
+    pub fn update(&mut self, msg: &[u8]) {
+        self.inner.update(msg);
+    }
+
+    /// Return the authentication tag for everything absorbed so far.
+    pub fn finalize(self) -> H::Output {
+        let inner = self.inner.finalize();
+        let mut outer = self.outer_init;
+        outer.update(inner.as_ref());
+        outer.finalize()
+    }
+
+    /// Discard the absorbed message, keeping the keyed pad states.
+    pub fn reset(&mut self) {
+        self.inner = self.inner_init.clone();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::hmac::hmac_sha256;
+
+    #[test]
+    fn matches_rfc4231_vectors_in_one_update() {
+        let long_key = vec![0xaa; 131];
+        let key_4: Vec<u8> = (0x01..=0x19).collect();
+        let cases: [(&[u8], &[u8]); 7] = [
+            (&[0x0b; 20], b"Hi There"),
+            (b"Jefe", b"what do ya want for nothing?"),
+            (&[0xaa; 20], &[0xdd; 50]),
+            (&key_4, &[0xcd; 50]),
+            (&[0x0c; 20], b"Test With Truncation"),
+            (&long_key, b"Test Using Larger Than Block-Size Key - Hash Key First"),
+            (&long_key, b"This is a test using a larger than block-size key and \
+            a larger than block-size data. The key needs to be hashed before \
+            being used by the HMAC algorithm."),
+        ];
+
+        for (key, msg) in cases {
+            let mut mac = HmacSha256::new(key);
+            mac.update(msg);
+
+            assert_eq!((mac.finalize()), (hmac_sha256(key, msg)));
+        }
+    }
+
+    #[test]
+    fn split_updates_match_one_shot() {
+        let key = b"key";
+        let msg = b"The quick brown fox jumps over the lazy dog";
+
+        for split in 0..=msg.len() {
+            let mut mac = HmacSha256::new(key);
+            mac.update(&msg[..split]);
+            mac.update(&msg[split..]);
+
+            assert_eq!((mac.finalize()), (hmac_sha256(key, msg)));
+        }
+    }
+
+    #[test]
+    fn reset_discards_absorbed_message() {
+        let mut mac = HmacSha256::new(b"Jefe");
+        mac.update(b"garbage that should not count");
+        mac.reset();
+        mac.update(b"what do ya want for nothing?");
+
+        let result = mac.finalize();
+        let expected = hmac_sha256(b"Jefe", b"what do ya want for nothing?");
+
+        assert_eq!((result), (expected));
+    }
+
+    #[test]
+    fn cloned_keyed_state_macs_several_messages() {
+        let keyed = HmacSha256::new(&[0x0b; 20]);
+
+        for msg in [&b"first"[..], b"second", b""] {
+            let mut mac = keyed.clone();
+            mac.update(msg);
+
+            assert_eq!((mac.finalize()), (hmac_sha256(&[0x0b; 20], msg)));
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use core::fmt;
+use crate::hash::HashFunction;
+use crate::hmac::{Hmac, HmacSha256};
+use crate::utils::ct_eq;
+
+/// Reasons a MAC tag is rejected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MacError {
+    /// The tag is shorter than the minimum accepted truncation.
+    TagTooShort,
+    /// The tag is longer than the hash output.
+    TagTooLong,
+    /// The tag does not match the message.
+    Mismatch,
+}
+
+impl fmt::Display for MacError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MacError::TagTooShort => write!(f, "tag is shorter than the minimum length"),
+            MacError::TagTooLong => write!(f, "tag is longer than the hash output"),
+            MacError::Mismatch => write!(f, "tag does not match"),
+        }
+    }
+}
+
+impl std::error::Error for MacError {}
+
+/// Minimum accepted tag length in bytes for HMAC over `H`.
+///
+/// RFC 2104, section 5: a truncated tag must keep at least half of the hash
+/// output and no less than 80 bits (16 bytes for HMAC-SHA-256).
+pub fn min_tag_len<H: HashFunction>() -> usize {
+    let half = H::OUTPUT_SIZE / 2;
+    if half > 10 { half } else { 10 }
+}
+
+impl<H: HashFunction> Hmac<H> {
+    /// Check `tag` against the MAC of everything absorbed so far.
+    ///
+    /// # Description
+    /// - Reject tags longer than the hash output, or shorter than 
+    ///   `min_tag_len`.
+    /// - Compare the tag with the leftmost `tag.len()` bytes of the MAC in 
+    ///   constant time.
+    pub fn verify(self, tag: &[u8]) -> Result<(), MacError> {
+        if tag.len() > H::OUTPUT_SIZE {
+            return Err(MacError::TagTooLong);
+        }
+        if tag.len() < min_tag_len::<H>() {
+            return Err(MacError::TagTooShort);
+        }
+
+        let mac = self.finalize();
+        if ct_eq(&mac.as_ref()[..tag.len()], tag) {
+            Ok(())
+        } else {
+            Err(MacError::Mismatch)
+        }
+    }
+}
+
+/// Verify an HMAC-SHA-256 tag in constant time.
+///
+/// # Arguments
+/// - `key`: Key as a byte slice (`&[u8]`), of any length.
+/// - `msg`: Message as a byte slice (`&[u8]`).
+/// - `tag`: Full 32-byte tag, or a truncation of its leftmost bytes of at 
+///   least 16 bytes.
+///
+/// # Returns
+/// `Ok(())` if the tag matches, or the `MacError` explaining the rejection.
+///
+/// # References
+/// - [RFC 2104, section 5](https://www.rfc-editor.org/rfc/rfc2104#section-5)  
+/// - [RFC 4231, section 4.6](https://www.rfc-editor.org/rfc/rfc4231#section-4.6)
+pub fn verify(key: &[u8], msg: &[u8], tag: &[u8]) -> Result<(), MacError> {
+    let mut mac = HmacSha256::new(key);
+    mac.update(msg);
+    mac.verify(tag)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::hmac::hmac_sha256;
+    use crate::sha256::Sha256;
+
+    #[test]
+    fn accepts_full_tag() {
+        let tag = hmac_sha256(b"Jefe", b"what do ya want for nothing?");
+
+        let result = verify(b"Jefe", b"what do ya want for nothing?", &tag);
+
+        assert_eq!((result), (Ok(())));
+    }
+
+    #[test]
+    fn rejects_tag_with_one_flipped_bit() {
+        let mut tag = hmac_sha256(b"Jefe", b"what do ya want for nothing?");
+        tag[31] ^= 0x01;
+
+        let result = verify(b"Jefe", b"what do ya want for nothing?", &tag);
+
+        assert_eq!((result), (Err(MacError::Mismatch)));
+    }
+
+    #[test]
+    fn rfc4231_case5_accepts_128_bit_truncation() {
+        let tag = [
+            0xa3, 0xb6, 0x16, 0x74, 0x73, 0x10, 0x0e, 0xe0,
+            0x6e, 0x0c, 0x79, 0x6c, 0x29, 0x55, 0x55, 0x2b,
+        ];
+
+        let result = verify(&[0x0c; 20], b"Test With Truncation", &tag);
+
+        assert_eq!((result), (Ok(())));
+    }
+
+    #[test]
+    fn rejects_truncation_below_minimum_even_if_prefix_matches() {
+        let tag = hmac_sha256(&[0x0c; 20], b"Test With Truncation");
+
+        for len in [0, 1, 4, 10, 15] {
+            let result = verify(&[0x0c; 20], b"Test With Truncation", &tag[..len]);
+
+            assert_eq!((result), (Err(MacError::TagTooShort)));
+        }
+    }
+
+    #[test]
+    fn rejects_tag_longer_than_output() {
+        let tag = [0u8; 33];
+
+        let result = verify(b"key", b"msg", &tag);
+
+        assert_eq!((result), (Err(MacError::TagTooLong)));
+    }
+
+    #[test]
+    fn minimum_tag_length_is_half_the_output() {
+        assert_eq!((min_tag_len::<Sha256>()), (16));
+    }
+}
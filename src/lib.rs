@@ -1,4 +1,11 @@
+pub mod hash;
 pub mod sha256;
+pub mod sha512;
 pub mod hmac;
+pub mod hkdf;
+pub mod pbkdf2;
 pub mod utils;
-pub mod merkle_tree;
\ No newline at end of file
+pub mod merkle_tree;
+
+#[cfg(test)]
+mod test_util;
@@ -0,0 +1,207 @@
+use crate::merkle_tree::MerkleMode;
+
+/// Append-only Merkle accumulator that keeps only the right frontier.
+///
+/// # Description
+/// - `frontier[k]` holds the root of a complete subtree of 2^k leaves, and is
+///   set exactly when bit k of the leaf count is set, so memory is
+///   O(log n).
+/// - `push` merges equal-sized subtrees like a binary counter increment.
+/// - `root` folds the frontier from the smallest subtree up, duplicating or
+///   promoting unpaired nodes according to the `MerkleMode`, so it equals
+///   the root of a `MerkleTree` built from the same leaves.
+///
+/// # Reference
+/// [RFC 9162, section 2.1.1](https://www.rfc-editor.org/rfc/rfc9162#section-2.1.1)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MerkleAccumulator {
+    mode: MerkleMode,
+    frontier: Vec<Option<[u8; 32]>>,
+    count: u64,
+}
+
+impl MerkleAccumulator {
+    /// Create an empty accumulator in the default `DuplicateLast` mode.
+    pub fn new() -> Self {
+        Self::with_mode(MerkleMode::DuplicateLast)
+    }
+
+    /// Create an empty accumulator in the given `mode`.
+    pub fn with_mode(mode: MerkleMode) -> Self {
+        MerkleAccumulator { mode, frontier: Vec::new(), count: 0 }
+    }
+
+    /// Mode the accumulator hashes with.
+    pub fn mode(&self) -> MerkleMode {
+        self.mode
+    }
+
+    /// Number of leaves pushed so far.
+    pub fn len(&self) -> u64 {
+        self.count
+    }
+
+    /// `true` if no leaf has been pushed yet.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Append a leaf.
+    ///
+    /// # Arguments
+    /// - `leaf` - the raw leaf record, hashed with `mode.hash_leaf`.
+    ///
+    /// # Description
+    /// While a complete subtree of the same size is waiting on the frontier,
+    /// combine it (on the left) with the new node and carry the result one
+    /// level up.
+    ///
+    /// # Returns
+    /// Index of the new leaf, or `None` (accumulator unchanged) if `leaf` 
+    /// cannot be hashed in the mode (not 32 bytes in `Bitcoin` mode).
+    pub fn push(&mut self, leaf: &[u8]) -> Option<u64> {
+        let mut node = self.mode.hash_leaf(leaf)?;
+        let mut k = 0;
+        while k < self.frontier.len() {
+            match self.frontier[k].take() {
+                Some(left) => node = self.mode.hash_node(left, node),
+                None => break,
+            }
+            k += 1;
+        }
+
+        if k == self.frontier.len() {
+            self.frontier.push(None);
+        }
+        self.frontier[k] = Some(node);
+        self.count += 1;
+        Some(self.count - 1)
+    }
+
+    /// Merkle root of every leaf pushed so far.
+    ///
+    /// # Description
+    /// - Walk the levels of the equivalent tree from the leaves up, carrying
+    ///   the root of the trailing partial subtree.
+    /// - On level k the carry is paired with `frontier[k]` when that is set,
+    ///   otherwise it is the unpaired last node of an odd level.
+    /// - Without a carry, a set `frontier[k]` is itself the unpaired last
+    ///   node.
+    ///
+    /// # Returns
+    /// The root, or `None` if the accumulator is empty.
+    pub fn root(&self) -> Option<[u8; 32]> {
+        if self.count == 0 {
+            return None;
+        }
+
+        let mut carry: Option<[u8; 32]> = None;
+        let mut k = 0;
+        loop {
+            // Nodes on level k: complete subtrees plus the partial one.
+            let mut width = self.count >> k;
+            if carry.is_some() {
+                width += 1;
+            }
+            if width == 1 && !(k == 0 && self.mode.pairs_single_leaf()) {
+                break;
+            }
+
+            carry = match (self.frontier[k], carry) {
+                (Some(left), Some(right)) => Some(self.mode.hash_node(left, right)),
+                (None, Some(node)) | (Some(node), None) => {
+                    Some(self.mode.hash_unpaired(node))
+                }
+                (None, None) => None,
+            };
+            k += 1;
+        }
+
+        match carry {
+            Some(node) => Some(node),
+            None => self.frontier[k],
+        }
+    }
+}
+
+impl Default for MerkleAccumulator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::leaves;
+    use crate::merkle_tree::{merkle_tree, MerkleTree};
+
+    #[test]
+    fn empty_accumulator_has_no_root() {
+        let acc = MerkleAccumulator::new();
+
+        assert!(acc.is_empty());
+        assert_eq!((acc.root()), (None));
+    }
+
+    #[test]
+    fn root_matches_merkle_tree_after_every_push() {
+        let data = leaves(70);
+        let mut acc = MerkleAccumulator::new();
+        for n in 1..=data.len() {
+            let index = acc.push(&data[n - 1]);
+
+            assert_eq!((index), (Some(n as u64 - 1)));
+            assert_eq!((acc.root()), (Some(merkle_tree(data[..n].to_vec()))));
+        }
+    }
+
+    #[test]
+    fn root_matches_stored_tree_in_every_mode() {
+        let data = leaves(70);
+        let modes = [MerkleMode::DuplicateLast, MerkleMode::Rfc6962, MerkleMode::Bitcoin];
+        for mode in modes {
+            let mut acc = MerkleAccumulator::with_mode(mode);
+            for n in 1..=data.len() {
+                acc.push(&data[n - 1]);
+                let tree = MerkleTree::with_mode(&data[..n], mode).unwrap();
+
+                assert_eq!((acc.root()), (Some(tree.root())));
+                assert_eq!((acc.len()), (n as u64));
+            }
+        }
+    }
+
+    #[test]
+    fn bitcoin_push_rejects_leaf_that_is_not_a_txid() {
+        let data = leaves(3);
+        let mut acc = MerkleAccumulator::with_mode(MerkleMode::Bitcoin);
+        acc.push(&data[0]);
+        acc.push(&data[1]);
+        let before = acc.clone();
+
+        assert_eq!((acc.push(&data[2][..31])), (None));
+        assert_eq!((acc.push(&[0u8; 33])), (None));
+        assert_eq!((acc), (before));
+        assert_eq!((acc.push(&data[2])), (Some(2)));
+    }
+
+    #[test]
+    fn frontier_holds_one_subtree_per_set_bit() {
+        let mut acc = MerkleAccumulator::with_mode(MerkleMode::Rfc6962);
+        for i in 0..1000u32 {
+            acc.push(&i.to_be_bytes());
+        }
+
+        let mut filled = 0;
+        for node in &acc.frontier {
+            if node.is_some() {
+                filled += 1;
+            }
+        }
+
+        // 1000 = 0b1111101000.
+        assert_eq!((acc.frontier.len()), (10));
+        assert_eq!((filled), (1000u32.count_ones()));
+    }
+}
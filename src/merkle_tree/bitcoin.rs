@@ -0,0 +1,208 @@
+use crate::merkle_tree::{MerkleMode, MerkleTree};
+use crate::sha256::sha256;
+
+/// Double SHA-256, `SHA-256(SHA-256(msg))`.
+///
+/// # Arguments
+/// - `msg` - message to hash.
+///
+/// # Returns
+/// The 32-byte digest, in internal byte order.
+pub fn sha256d(msg: &[u8]) -> [u8; 32] {
+    sha256(&sha256(msg))
+}
+
+/// Convert a hash between internal byte order and display order.
+///
+/// # Description
+/// Bitcoin hashes txids and block headers in the byte order SHA-256d returns
+/// them (internal order), but block explorers and RPCs print them reversed 
+/// (display order). Reversing the 32 bytes converts either way.
+pub fn reverse_order(hash: [u8; 32]) -> [u8; 32] {
+    let mut out = [0u8; 32];
+    let mut i = 0;
+    while i < 32 {
+        out[i] = hash[31 - i];
+        i += 1;
+    }
+    out
+}
+
+/// Compute a block's merkle root from its txids.
+///
+/// # Arguments
+/// - `txids` - transaction ids in block order, in internal byte order.
+///
+/// # Description
+/// - Txids are already hashes, so they are used as leaves without hashing.
+/// - Parents are `SHA-256d(left || right)`; the last node of an odd level 
+///   is paired with itself.
+/// - With a single transaction the root is its txid.
+///
+/// # Returns
+/// The merkle root in internal byte order (as stored in the block header), 
+/// or `None` for an empty list.
+///
+/// # Reference
+/// [Bitcoin developer guide, merkle trees](https://developer.bitcoin.org/reference/block_chain.html#merkle-trees)
+pub fn bitcoin_merkle_root(txids: &[[u8; 32]]) -> Option<[u8; 32]> {
+    let tree = MerkleTree::with_mode(txids, MerkleMode::Bitcoin)?;
+    Some(tree.root())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::merkle_tree::verify_proof_with;
+
+    // Display-order txids, as shown by block explorers, to internal order.
+    fn internal(display: &[[u8; 32]]) -> Vec<[u8; 32]> {
+        let mut out = Vec::new();
+        for hash in display {
+            out.push(reverse_order(*hash));
+        }
+        out
+    }
+
+    #[test]
+    fn sha256d_hashes_twice() {
+        let result = sha256d(b"hello");
+        let expected = [
+            0x95, 0x95, 0xc9, 0xdf, 0x90, 0x07, 0x51, 0x48,
+            0xeb, 0x06, 0x86, 0x03, 0x65, 0xdf, 0x33, 0x58,
+            0x4b, 0x75, 0xbf, 0xf7, 0x82, 0xa5, 0x10, 0xc6,
+            0xcd, 0x48, 0x83, 0xa4, 0x19, 0x83, 0x3d, 0x50,
+        ];
+
+        assert_eq!((result), (expected));
+    }
+
+    #[test]
+    fn reverse_order_round_trips() {
+        let mut hash = [0u8; 32];
+        for (i, byte) in hash.iter_mut().enumerate() {
+            *byte = i as u8;
+        }
+
+        assert_eq!((reverse_order(hash)[0]), (31));
+        assert_eq!((reverse_order(reverse_order(hash))), (hash));
+    }
+
+    #[test]
+    fn genesis_block_root_is_the_coinbase_txid() {
+        // Block 0 has a single transaction.
+        let txid = [
+            0x4a, 0x5e, 0x1e, 0x4b, 0xaa, 0xb8, 0x9f, 0x3a,
+            0x32, 0x51, 0x8a, 0x88, 0xc3, 0x1b, 0xc8, 0x7f,
+            0x61, 0x8f, 0x76, 0x67, 0x3e, 0x2c, 0xc7, 0x7a,
+            0xb2, 0x12, 0x7b, 0x7a, 0xfd, 0xed, 0xa3, 0x3b,
+        ];
+
+        let result = bitcoin_merkle_root(&internal(&[txid])).unwrap();
+
+        assert_eq!((reverse_order(result)), (txid));
+    }
+
+    #[test]
+    fn block_170_root_matches_header() {
+        // First block with a non-coinbase transaction.
+        let txids = [
+            [
+                0xb1, 0xfe, 0xa5, 0x24, 0x86, 0xce, 0x0c, 0x62,
+                0xbb, 0x44, 0x2b, 0x53, 0x0a, 0x3f, 0x01, 0x32,
+                0xb8, 0x26, 0xc7, 0x4e, 0x47, 0x3d, 0x1f, 0x2c,
+                0x22, 0x0b, 0xfa, 0x78, 0x11, 0x1c, 0x50, 0x82,
+            ],
+            [
+                0xf4, 0x18, 0x4f, 0xc5, 0x96, 0x40, 0x3b, 0x9d,
+                0x63, 0x87, 0x83, 0xcf, 0x57, 0xad, 0xfe, 0x4c,
+                0x75, 0xc6, 0x05, 0xf6, 0x35, 0x6f, 0xbc, 0x91,
+                0x33, 0x85, 0x30, 0xe9, 0x83, 0x1e, 0x9e, 0x16,
+            ],
+        ];
+        let header_root = [
+            0x7d, 0xac, 0x2c, 0x56, 0x66, 0x81, 0x5c, 0x17,
+            0xa3, 0xb3, 0x64, 0x27, 0xde, 0x37, 0xbb, 0x9d,
+            0x2e, 0x2c, 0x5c, 0xce, 0xc3, 0xf8, 0x63, 0x3e,
+            0xb9, 0x1a, 0x42, 0x05, 0xcb, 0x4c, 0x10, 0xff,
+        ];
+
+        let result = bitcoin_merkle_root(&internal(&txids)).unwrap();
+
+        assert_eq!((reverse_order(result)), (header_root));
+    }
+
+    #[test]
+    fn block_100000_root_matches_header() {
+        let txids = [
+            [
+                0x8c, 0x14, 0xf0, 0xdb, 0x3d, 0xf1, 0x50, 0x12,
+                0x3e, 0x6f, 0x3d, 0xbb, 0xf3, 0x0f, 0x8b, 0x95,
+                0x5a, 0x82, 0x49, 0xb6, 0x2a, 0xc1, 0xd1, 0xff,
+                0x16, 0x28, 0x4a, 0xef, 0xa3, 0xd0, 0x6d, 0x87,
+            ],
+            [
+                0xff, 0xf2, 0x52, 0x5b, 0x89, 0x31, 0x40, 0x2d,
+                0xd0, 0x92, 0x22, 0xc5, 0x07, 0x75, 0x60, 0x8f,
+                0x75, 0x78, 0x7b, 0xd2, 0xb8, 0x7e, 0x56, 0x99,
+                0x5a, 0x7b, 0xdd, 0x30, 0xf7, 0x97, 0x02, 0xc4,
+            ],
+            [
+                0x63, 0x59, 0xf0, 0x86, 0x81, 0x71, 0xb1, 0xd1,
+                0x94, 0xcb, 0xee, 0x1a, 0xf2, 0xf1, 0x6e, 0xa5,
+                0x98, 0xae, 0x8f, 0xad, 0x66, 0x6d, 0x9b, 0x01,
+                0x2c, 0x8e, 0xd2, 0xb7, 0x9a, 0x23, 0x6e, 0xc4,
+            ],
+            [
+                0xe9, 0xa6, 0x68, 0x45, 0xe0, 0x5d, 0x5a, 0xbc,
+                0x0a, 0xd0, 0x4e, 0xc8, 0x0f, 0x77, 0x4a, 0x7e,
+                0x58, 0x5c, 0x6e, 0x8d, 0xb9, 0x75, 0x96, 0x2d,
+                0x06, 0x9a, 0x52, 0x21, 0x37, 0xb8, 0x0c, 0x1d,
+            ],
+        ];
+        let header_root = [
+            0xf3, 0xe9, 0x47, 0x42, 0xac, 0xa4, 0xb5, 0xef,
+            0x85, 0x48, 0x8d, 0xc3, 0x7c, 0x06, 0xc3, 0x28,
+            0x22, 0x95, 0xff, 0xec, 0x96, 0x09, 0x94, 0xb2,
+            0xc0, 0xd5, 0xac, 0x2a, 0x25, 0xa9, 0x57, 0x66,
+        ];
+
+        let result = bitcoin_merkle_root(&internal(&txids)).unwrap();
+
+        assert_eq!((reverse_order(result)), (header_root));
+    }
+
+    #[test]
+    fn odd_txid_count_duplicates_the_last_txid() {
+        let txids = [[0x01u8; 32], [0x02u8; 32], [0x03u8; 32]];
+        let left = sha256d(&[&txids[0][..], &txids[1]].concat());
+        let right = sha256d(&[&txids[2][..], &txids[2]].concat());
+
+        let result = bitcoin_merkle_root(&txids).unwrap();
+        let expected = sha256d(&[&left[..], &right].concat());
+
+        assert_eq!((result), (expected));
+    }
+
+    #[test]
+    fn bitcoin_proofs_verify_against_block_root() {
+        let mut txids = Vec::new();
+        for i in 0..5u8 {
+            txids.push([i + 1; 32]);
+        }
+        let tree = MerkleTree::with_mode(&txids, MerkleMode::Bitcoin).unwrap();
+
+        for (i, txid) in txids.iter().enumerate() {
+            let proof = tree.proof(i).unwrap();
+
+            assert!(verify_proof_with(
+                MerkleMode::Bitcoin, tree.root(), txid, i, &proof
+            ));
+        }
+    }
+
+    #[test]
+    fn empty_block_has_no_root() {
+        assert_eq!((bitcoin_merkle_root(&[])), (None));
+    }
+}
@@ -1,4 +1,5 @@
-use crate::sha256::sha256;
+use crate::hash::HashFunction;
+use crate::sha256::Sha256;
 
 /// Merkle-tree branching: combine left and right nodes into a parent node.
 ///
@@ -14,23 +15,35 @@ use crate::sha256::sha256;
 /// # Returns
 /// - `[u8; 32]` - the parent node hash.
 pub fn branching(left_node: [u8; 32], right_node: [u8; 32]) -> [u8; 32] {
-    let mut combined = Vec::with_capacity(64);
+    branching_with::<Sha256>(left_node, right_node)
+}
 
-    for i in 0..left_node.len() {
-        combined.push(left_node[i]);
-    }
+/// Merkle-tree branching over any `HashFunction` `H`.
+///
+/// # Returns
+/// - `H::Output` - the parent node hash `H(left || right)`.
+pub fn branching_with<H: HashFunction>(
+    left_node: H::Output, 
+    right_node: H::Output,
+) -> H::Output {
+    let left = left_node.as_ref();
+    let right = right_node.as_ref();
+    let mut combined = Vec::with_capacity(left.len() + right.len());
 
-    for j in 0..right_node.len() {
-        combined.push(right_node[j]);
-    }
+    combined.extend_from_slice(left);
+    combined.extend_from_slice(right);
 
-    sha256(&combined)
+    H::digest(&combined)
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::sha256::sha256;
+    use crate::sha512::{sha512, Sha512};
+
     #[test]
+    #[allow(clippy::needless_range_loop)]
     fn append_hashed_leafs_1by1_and_hash_appended_computation() {
         let a = [0xccu8; 32];
         let b = [0x1du8; 32];
@@ -49,4 +62,15 @@ mod test {
 
         assert_eq!((result), (expected));
     }
+
+    #[test]
+    fn branching_with_sha512_hashes_64_byte_children() {
+        let a = [0xccu8; 64];
+        let b = [0x1du8; 64];
+
+        let result = branching_with::<Sha512>(a, b);
+        let expected = sha512(&[a, b].concat());
+
+        assert_eq!((result), (expected));
+    }
 }
\ No newline at end of file
@@ -0,0 +1,360 @@
+use crate::merkle_tree::{MerkleMode, MerkleTree};
+
+/// Consistency proof between two sizes of an append-only `Rfc6962` tree.
+///
+/// # Description
+/// - Proves that the first `old_size` leaves of the tree of `new_size` 
+///   leaves are exactly the leaves of the older tree, i.e. nothing logged 
+///   before `old_size` was rewritten.
+/// - `nodes` are subtree hashes in the order of `SUBPROOF` from RFC 6962.
+///
+/// # Reference
+/// [RFC 9162, section 2.1.4](https://www.rfc-editor.org/rfc/rfc9162#section-2.1.4)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConsistencyProof {
+    pub old_size: usize,
+    pub new_size: usize,
+    pub nodes: Vec<[u8; 32]>,
+}
+
+impl MerkleTree {
+    /// Consistency proof from the tree's first `old_size` leaves to the 
+    /// whole tree.
+    ///
+    /// # Returns
+    /// `None` if the tree is not in `Rfc6962` mode, or if `old_size` is 0 or
+    /// larger than the tree.
+    pub fn consistency_proof(&self, old_size: usize) -> Option<ConsistencyProof> {
+        if self.mode() != MerkleMode::Rfc6962 
+            || old_size == 0 
+            || old_size > self.len() 
+        {
+            return None;
+        }
+
+        let mut nodes: Vec<[u8; 32]> = Vec::new();
+        self.subproof(old_size, 0, self.len(), true, &mut nodes);
+
+        Some(ConsistencyProof { old_size, new_size: self.len(), nodes })
+    }
+
+    /// `SUBPROOF(m, D[start:end], b)` from RFC 6962, appended to `nodes`.
+    fn subproof(
+        &self, 
+        m: usize, 
+        start: usize, 
+        end: usize, 
+        complete: bool, 
+        nodes: &mut Vec<[u8; 32]>,
+    ) {
+        let n = end - start;
+        if m == n {
+            // The old tree is this whole subtree; its root is known to the 
+            // verifier only at the top.
+            if !complete {
+                nodes.push(self.subtree_hash(start, end));
+            }
+            return;
+        }
+
+        let k = split_point(n);
+        if m <= k {
+            self.subproof(m, start, start + k, complete, nodes);
+            nodes.push(self.subtree_hash(start + k, end));
+        } else {
+            self.subproof(m - k, start + k, end, false, nodes);
+            nodes.push(self.subtree_hash(start, start + k));
+        }
+    }
+
+    /// `MTH(D[start:end])`, read from the stored levels when the range is a
+    /// stored node, otherwise split like RFC 6962.
+    fn subtree_hash(&self, start: usize, end: usize) -> [u8; 32] {
+        // Node i of level n covers leaves [i * 2^n, min((i + 1) * 2^n, len)),
+        // since unpaired nodes are promoted.
+        let mut n = 0;
+        while n < self.depth() {
+            let width = 1usize << n;
+            if start.is_multiple_of(width) && end == (start + width).min(self.len()) {
+                return self.level(n).unwrap()[start >> n];
+            }
+            n += 1;
+        }
+
+        let k = split_point(end - start);
+        self.mode().hash_node(
+            self.subtree_hash(start, start + k), 
+            self.subtree_hash(start + k, end),
+        )
+    }
+}
+
+/// Largest power of two strictly smaller than `n` (for `n` > 1).
+fn split_point(n: usize) -> usize {
+    let mut k = 1;
+    while k * 2 < n {
+        k *= 2;
+    }
+    k
+}
+
+/// Verify a consistency proof between two roots of an `Rfc6962` tree.
+///
+/// # Arguments
+/// - `old_root` - root of the tree at `proof.old_size`.
+/// - `new_root` - root of the tree at `proof.new_size`.
+/// - `proof` - proof produced by `MerkleTree::consistency_proof`.
+///
+/// # Description
+/// - Equal sizes need an empty proof and equal roots.
+/// - Otherwise rebuild both roots from the proof nodes, following the 
+///   verification algorithm of RFC 9162, section 2.1.4.2.
+///
+/// # Returns
+/// `true` if the old tree is a prefix of the new tree.
+pub fn verify_consistency(
+    old_root: [u8; 32], 
+    new_root: [u8; 32], 
+    proof: &ConsistencyProof,
+) -> bool {
+    let mode = MerkleMode::Rfc6962;
+    if proof.old_size == 0 || proof.old_size > proof.new_size {
+        return false;
+    }
+    if proof.old_size == proof.new_size {
+        return proof.nodes.is_empty() && old_root == new_root;
+    }
+
+    // A power-of-two old tree is a complete subtree, so its root is the 
+    // first node of the path.
+    let mut path: Vec<[u8; 32]> = Vec::new();
+    if proof.old_size.is_power_of_two() {
+        path.push(old_root);
+    }
+    for node in &proof.nodes {
+        path.push(*node);
+    }
+    if path.is_empty() {
+        return false;
+    }
+
+    let mut f_n = proof.old_size - 1;
+    let mut s_n = proof.new_size - 1;
+    while f_n % 2 == 1 {
+        f_n >>= 1;
+        s_n >>= 1;
+    }
+
+    let mut f_r = path[0];
+    let mut s_r = path[0];
+    let mut i = 1;
+    while i < path.len() {
+        if s_n == 0 {
+            return false;
+        }
+
+        let c = path[i];
+        if f_n % 2 == 1 || f_n == s_n {
+            f_r = mode.hash_node(c, f_r);
+            s_r = mode.hash_node(c, s_r);
+            while f_n.is_multiple_of(2) && f_n != 0 {
+                f_n >>= 1;
+                s_n >>= 1;
+            }
+        } else {
+            s_r = mode.hash_node(s_r, c);
+        }
+        f_n >>= 1;
+        s_n >>= 1;
+        i += 1;
+    }
+
+    s_n == 0 && f_r == old_root && s_r == new_root
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::leaves;
+
+    // Leaf data used by the Certificate Transparency reference tests.
+    fn ct_leaves() -> Vec<Vec<u8>> {
+        vec![
+            vec![],
+            vec![0x00],
+            vec![0x10],
+            vec![0x20, 0x21],
+            vec![0x30, 0x31],
+            vec![0x40, 0x41, 0x42, 0x43],
+            vec![0x50, 0x51, 0x52, 0x53, 0x54, 0x55, 0x56, 0x57],
+            vec![
+                0x60, 0x61, 0x62, 0x63, 0x64, 0x65, 0x66, 0x67,
+                0x68, 0x69, 0x6a, 0x6b, 0x6c, 0x6d, 0x6e, 0x6f,
+            ],
+        ]
+    }
+
+    fn ct_tree(n: usize) -> MerkleTree {
+        MerkleTree::from_data_iter(&ct_leaves()[..n], MerkleMode::Rfc6962).unwrap()
+    }
+
+    #[test]
+    fn ct_reference_roots() {
+        let root_5 = [
+            0x4e, 0x3b, 0xbb, 0x1f, 0x7b, 0x47, 0x8d, 0xcf,
+            0xe7, 0x1f, 0xb6, 0x31, 0x63, 0x15, 0x19, 0xa3,
+            0xbc, 0xa1, 0x2c, 0x9a, 0xef, 0xca, 0x16, 0x12,
+            0xbf, 0xce, 0x4c, 0x13, 0xa8, 0x62, 0x64, 0xd4,
+        ];
+        let root_8 = [
+            0x5d, 0xc9, 0xda, 0x79, 0xa7, 0x06, 0x59, 0xa9,
+            0xad, 0x55, 0x9c, 0xb7, 0x01, 0xde, 0xd9, 0xa2,
+            0xab, 0x9d, 0x82, 0x3a, 0xad, 0x2f, 0x49, 0x60,
+            0xcf, 0xe3, 0x70, 0xef, 0xf4, 0x60, 0x43, 0x28,
+        ];
+
+        assert_eq!((ct_tree(5).root()), (root_5));
+        assert_eq!((ct_tree(8).root()), (root_8));
+    }
+
+    #[test]
+    fn ct_reference_proof_1_to_8() {
+        let proof = ct_tree(8).consistency_proof(1).unwrap();
+        let expected = vec![
+            [
+                0x96, 0xa2, 0x96, 0xd2, 0x24, 0xf2, 0x85, 0xc6,
+                0x7b, 0xee, 0x93, 0xc3, 0x0f, 0x8a, 0x30, 0x91,
+                0x57, 0xf0, 0xda, 0xa3, 0x5d, 0xc5, 0xb8, 0x7e,
+                0x41, 0x0b, 0x78, 0x63, 0x0a, 0x09, 0xcf, 0xc7,
+            ],
+            [
+                0x5f, 0x08, 0x3f, 0x0a, 0x1a, 0x33, 0xca, 0x07,
+                0x6a, 0x95, 0x27, 0x98, 0x32, 0x58, 0x0d, 0xb3,
+                0xe0, 0xef, 0x45, 0x84, 0xbd, 0xff, 0x1f, 0x54,
+                0xc8, 0xa3, 0x60, 0xf5, 0x0d, 0xe3, 0x03, 0x1e,
+            ],
+            [
+                0x6b, 0x47, 0xaa, 0xf2, 0x9e, 0xe3, 0xc2, 0xaf,
+                0x9a, 0xf8, 0x89, 0xbc, 0x1f, 0xb9, 0x25, 0x4d,
+                0xab, 0xd3, 0x11, 0x77, 0xf1, 0x62, 0x32, 0xdd,
+                0x6a, 0xab, 0x03, 0x5c, 0xa3, 0x9b, 0xf6, 0xe4,
+            ],
+        ];
+
+        assert_eq!((proof.nodes), (expected));
+        assert!(verify_consistency(ct_tree(1).root(), ct_tree(8).root(), &proof));
+    }
+
+    #[test]
+    fn ct_reference_proof_6_to_8() {
+        let proof = ct_tree(8).consistency_proof(6).unwrap();
+        let expected = vec![
+            [
+                0x0e, 0xbc, 0x5d, 0x34, 0x37, 0xfb, 0xe2, 0xdb,
+                0x15, 0x8b, 0x9f, 0x12, 0x6a, 0x1d, 0x11, 0x8e,
+                0x30, 0x81, 0x81, 0x03, 0x1d, 0x0a, 0x94, 0x9f,
+                0x8d, 0xed, 0xed, 0xeb, 0xc5, 0x58, 0xef, 0x6a,
+            ],
+            [
+                0xca, 0x85, 0x4e, 0xa1, 0x28, 0xed, 0x05, 0x0b,
+                0x41, 0xb3, 0x5f, 0xfc, 0x1b, 0x87, 0xb8, 0xeb,
+                0x2b, 0xde, 0x46, 0x1e, 0x9e, 0x3b, 0x55, 0x96,
+                0xec, 0xe6, 0xb9, 0xd5, 0x97, 0x5a, 0x0a, 0xe0,
+            ],
+            [
+                0xd3, 0x7e, 0xe4, 0x18, 0x97, 0x6d, 0xd9, 0x57,
+                0x53, 0xc1, 0xc7, 0x38, 0x62, 0xb9, 0x39, 0x8f,
+                0xa2, 0xa2, 0xcf, 0x9b, 0x4f, 0xf0, 0xfd, 0xfe,
+                0x8b, 0x30, 0xcd, 0x95, 0x20, 0x96, 0x14, 0xb7,
+            ],
+        ];
+
+        assert_eq!((proof.nodes), (expected));
+        assert!(verify_consistency(ct_tree(6).root(), ct_tree(8).root(), &proof));
+    }
+
+    #[test]
+    fn ct_reference_proof_2_to_5() {
+        let proof = ct_tree(5).consistency_proof(2).unwrap();
+        let expected = vec![
+            [
+                0x5f, 0x08, 0x3f, 0x0a, 0x1a, 0x33, 0xca, 0x07,
+                0x6a, 0x95, 0x27, 0x98, 0x32, 0x58, 0x0d, 0xb3,
+                0xe0, 0xef, 0x45, 0x84, 0xbd, 0xff, 0x1f, 0x54,
+                0xc8, 0xa3, 0x60, 0xf5, 0x0d, 0xe3, 0x03, 0x1e,
+            ],
+            [
+                0xbc, 0x1a, 0x06, 0x43, 0xb1, 0x2e, 0x4d, 0x2d,
+                0x7c, 0x77, 0x91, 0x8f, 0x44, 0xe0, 0xf4, 0xf7,
+                0x9a, 0x83, 0x8b, 0x6c, 0xf9, 0xec, 0x5b, 0x5c,
+                0x28, 0x3e, 0x1f, 0x4d, 0x88, 0x59, 0x9e, 0x6b,
+            ],
+        ];
+
+        assert_eq!((proof.nodes), (expected));
+        assert!(verify_consistency(ct_tree(2).root(), ct_tree(5).root(), &proof));
+    }
+
+    #[test]
+    fn same_size_proof_is_empty() {
+        let tree = ct_tree(8);
+        let proof = tree.consistency_proof(8).unwrap();
+
+        assert_eq!((proof.nodes.len()), (0));
+        assert!(verify_consistency(tree.root(), tree.root(), &proof));
+        assert!(!verify_consistency(ct_tree(7).root(), tree.root(), &proof));
+    }
+
+    #[test]
+    fn every_prefix_is_consistent_with_every_larger_tree() {
+        let data = leaves(33);
+        for n in 1..=data.len() {
+            let new = MerkleTree::with_mode(&data[..n], MerkleMode::Rfc6962).unwrap();
+            for m in 1..=n {
+                let old = MerkleTree::with_mode(&data[..m], MerkleMode::Rfc6962).unwrap();
+                let proof = new.consistency_proof(m).unwrap();
+
+                assert!(verify_consistency(old.root(), new.root(), &proof));
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_rewritten_history_or_tampered_proof() {
+        let data = leaves(11);
+        let new = MerkleTree::with_mode(&data, MerkleMode::Rfc6962).unwrap();
+        let mut rewritten = data[..6].to_vec();
+        rewritten[2][0] ^= 0x01;
+        let old = MerkleTree::with_mode(&rewritten, MerkleMode::Rfc6962).unwrap();
+        let proof = new.consistency_proof(6).unwrap();
+
+        assert!(!verify_consistency(old.root(), new.root(), &proof));
+
+        let honest = MerkleTree::with_mode(&data[..6], MerkleMode::Rfc6962).unwrap();
+        for i in 0..proof.nodes.len() {
+            let mut bad = proof.clone();
+            bad.nodes[i][31] ^= 0x80;
+
+            assert!(!verify_consistency(honest.root(), new.root(), &bad));
+        }
+
+        let mut bad = proof.clone();
+        bad.nodes.pop();
+        assert!(!verify_consistency(honest.root(), new.root(), &bad));
+
+        let mut bad = proof.clone();
+        bad.old_size = 5;
+        assert!(!verify_consistency(honest.root(), new.root(), &bad));
+    }
+
+    #[test]
+    fn only_rfc6962_trees_and_valid_sizes_have_proofs() {
+        let data = leaves(4);
+        let tree = MerkleTree::new(&data).unwrap();
+        let ct = MerkleTree::with_mode(&data, MerkleMode::Rfc6962).unwrap();
+
+        assert_eq!((tree.consistency_proof(2)), (None));
+        assert_eq!((ct.consistency_proof(0)), (None));
+        assert_eq!((ct.consistency_proof(5)), (None));
+    }
+}
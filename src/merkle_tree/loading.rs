@@ -1,4 +1,5 @@
-use crate::sha256::sha256;
+use crate::hash::HashFunction;
+use crate::sha256::Sha256;
 
 /// Merkle-tree leaf loading: to increment if needed, and hash transaction.
 ///
@@ -12,18 +13,26 @@ use crate::sha256::sha256;
 /// # Returns
 /// Hashed version of leaf nodes (hashed transactions).
 pub fn leaf_loading(leaf: &[[u8; 32]]) -> Vec<[u8; 32]> {
+    leaf_loading_with::<Sha256>(leaf)
+}
+
+/// Merkle-tree leaf loading over any `HashFunction` `H`.
+///
+/// # Returns
+/// Leaf nodes hashed with `H`, with the last one duplicated if odd.
+pub fn leaf_loading_with<H: HashFunction>(leaf: &[[u8; 32]]) -> Vec<H::Output> {
     let mut transactions = leaf.to_vec();
     
-    if transactions.len() % 2 != 0 {
+    if !transactions.len().is_multiple_of(2) {
         // Duplicate the last element if odd.
         let last_index = transactions[transactions.len() - 1];
         transactions.push(last_index);
     }; 
 
-    let mut leaf_nodes: Vec<[u8; 32]> = Vec::new();
+    let mut leaf_nodes: Vec<H::Output> = Vec::new();
     for i in &transactions {
         // Hash and store data.
-        let hashed_transaction = sha256(i);    
+        let hashed_transaction = H::digest(i);    
         leaf_nodes.push(hashed_transaction);
     }
 
@@ -33,6 +42,7 @@ pub fn leaf_loading(leaf: &[[u8; 32]]) -> Vec<[u8; 32]> {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::sha256::sha256;
 
     #[test] 
     fn load_and_hash_leaf_then_store_them() {
@@ -42,7 +52,7 @@ mod test {
         let ha = sha256(&a);
         let hb = sha256(&b);
 
-        let result = leaf_loading(&vec![a, b]);
+        let result = leaf_loading(&[a, b]);
         let expected = vec![ha, hb];
 
         assert_eq!((result), (expected))
@@ -58,7 +68,7 @@ mod test {
         let hb = sha256(&b);
         let hc = sha256(&c);
         
-        let result = leaf_loading(&vec![a, b, c]);
+        let result = leaf_loading(&[a, b, c]);
         let expected = vec![ha, hb, hc, hc];
 
         assert_eq!((result), (expected));
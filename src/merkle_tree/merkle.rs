@@ -1,4 +1,6 @@
-use crate::merkle_tree::{leaf_loading, branching};
+use crate::hash::HashFunction;
+use crate::merkle_tree::{leaf_loading_with, branching_with};
+use crate::sha256::Sha256;
 
 /// Merkle tree: for efficiently verifying data.
 ///
@@ -17,15 +19,34 @@ use crate::merkle_tree::{leaf_loading, branching};
 /// # Returns
 /// - `[u8; 32]` - the Merkle root of the tree.
 ///
+/// # Panics
+/// If `leaf` is empty, since an empty tree has no root. Use
+/// `MerkleTree::new`, which returns `None`, for input that may be empty.
+///
 /// # References
 /// - [Investopedia](https://www.investopedia.com/terms/m/merkle-tree.asp)  
 /// - [Bitcoin developer guide](https://developer.bitcoin.org/devguide/block_chain.html)
-fn merkle_tree(leaf: Vec<[u8; 32]>) -> [u8; 32] {
-    let mut leaf_nodes = leaf_loading(&leaf);
+pub fn merkle_tree(leaf: Vec<[u8; 32]>) -> [u8; 32] {
+    merkle_tree_with::<Sha256>(leaf)
+}
+
+/// Merkle tree over any `HashFunction` `H`.
+///
+/// # Description
+/// Same construction as `merkle_tree`, hashing leaves and parents with `H`.
+///
+/// # Returns
+/// - `H::Output` - the Merkle root of the tree.
+///
+/// # Panics
+/// If `leaf` is empty, since an empty tree has no root.
+pub fn merkle_tree_with<H: HashFunction>(leaf: Vec<[u8; 32]>) -> H::Output {
+    assert!(!leaf.is_empty(), "merkle tree needs at least one leaf");
+    let mut leaf_nodes = leaf_loading_with::<H>(&leaf);
 
     while leaf_nodes.len() > 1 {
-        let mut non_leaf_nodes: Vec<[u8; 32]> = Vec::new();
-        if leaf_nodes.len() % 2 != 0 {
+        let mut non_leaf_nodes: Vec<H::Output> = Vec::new();
+        if !leaf_nodes.len().is_multiple_of(2) {
             // Increment if odd.
             let last_index = leaf_nodes[leaf_nodes.len() - 1];
             leaf_nodes.push(last_index);
@@ -33,23 +54,24 @@ fn merkle_tree(leaf: Vec<[u8; 32]>) -> [u8; 32] {
 
         let mut i = 0;
         while i < leaf_nodes.len() {
-            let parent_node = branching(leaf_nodes[i], leaf_nodes[i + 1]);
+            let parent_node = branching_with::<H>(leaf_nodes[i], leaf_nodes[i + 1]);
             non_leaf_nodes.push(parent_node);
             // Get index per 2 (0-1, 2-4, 5-6, etc).
-            i = i + 2;
+            i += 2;
         }
 
         leaf_nodes = non_leaf_nodes;
     }
 
-    let merkle_root = leaf_nodes[0];
-    merkle_root
+    // Merkle root.
+    leaf_nodes[0]
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
     use crate::sha256::sha256;
+    use crate::sha512::{sha512, Sha512};
 
     #[test]
     fn load_even_leaf_then_branch_data_and_compute_root() {
@@ -116,4 +138,35 @@ mod test {
 
         assert_eq!((result), (expected));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn sha512_tree_hashes_leaves_and_parents_with_sha512() {
+        let a = [0x94u8; 32];
+        let b = [0x08u8; 32];
+        let c = [0x30u8; 32];
+
+        let ha = sha512(&a);
+        let hb = sha512(&b);
+        let hc = sha512(&c);
+
+        let hab = sha512(&[ha, hb].concat());
+        let hcc = sha512(&[hc, hc].concat());
+
+        let result = merkle_tree_with::<Sha512>(vec![a, b, c]);
+        let expected = sha512(&[hab, hcc].concat());
+
+        assert_eq!((result), (expected));
+    }
+
+    #[test]
+    #[should_panic]
+    fn tree_of_no_leaves_panics() {
+        merkle_tree(Vec::new());
+    }
+
+    #[test]
+    #[should_panic]
+    fn sha512_tree_of_no_leaves_panics() {
+        merkle_tree_with::<Sha512>(Vec::new());
+    }
+}
@@ -0,0 +1,516 @@
+use crate::merkle_tree::branching;
+use crate::sha256::sha256;
+
+/// Merkle Mountain Range: an append-only list of perfect Merkle trees.
+///
+/// # Description
+/// - Nodes are stored in post-order: each leaf is appended, followed by
+///   every parent it completes, so node positions never change.
+/// - Leaves are `sha256(leaf)` and parents are `branching(left, right)`.
+/// - After n leaves the range holds one perfect tree ("mountain") per set
+///   bit of n, largest first; their roots are the peaks.
+/// - The root bags the peaks from right to left:
+///   `branching(p0, branching(p1, ... branching(pk-1, pk)))`.
+/// - Because old nodes never change, a proof against a historic size keeps
+///   verifying against that size's root however many leaves are appended.
+///
+/// # Reference
+/// [MMR overview (Grin)](https://docs.grin.mw/wiki/chain-state/merkle-mountain-range/)
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Mmr {
+    nodes: Vec<[u8; 32]>,
+    leaves: u64,
+}
+
+/// Inclusion proof for one leaf of an `Mmr` at a given size.
+///
+/// # Description
+/// - `mmr_size` is the number of nodes the proof was made against.
+/// - `path` lists the siblings from the leaf up to its peak.
+/// - `peaks` lists the other peaks of that size, left to right; the peak of
+///   the leaf is recomputed from `path`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MmrProof {
+    pub mmr_size: u64,
+    pub path: Vec<[u8; 32]>,
+    pub peaks: Vec<[u8; 32]>,
+}
+
+impl Mmr {
+    /// Create an empty range.
+    pub fn new() -> Self {
+        Mmr { nodes: Vec::new(), leaves: 0 }
+    }
+
+    /// Number of leaves appended.
+    pub fn leaf_count(&self) -> u64 {
+        self.leaves
+    }
+
+    /// Number of stored nodes (leaves and parents), the MMR size.
+    pub fn size(&self) -> u64 {
+        self.nodes.len() as u64
+    }
+
+    /// `true` if no leaf has been appended.
+    pub fn is_empty(&self) -> bool {
+        self.leaves == 0
+    }
+
+    /// Node at position `pos`, or `None` if past the end.
+    pub fn node(&self, pos: u64) -> Option<[u8; 32]> {
+        if pos < self.size() {
+            Some(self.nodes[pos as usize])
+        } else {
+            None
+        }
+    }
+
+    /// Append a leaf and every parent it completes.
+    ///
+    /// # Returns
+    /// Position of the new leaf node.
+    pub fn append(&mut self, leaf: &[u8]) -> u64 {
+        let leaf_pos = self.size();
+        self.nodes.push(sha256(leaf));
+
+        // While the next position is a parent, merge the two mountains
+        // below it.
+        let mut pos = leaf_pos;
+        let mut h = 0;
+        while pos_height(pos + 1) > h {
+            let left = pos + 1 - (2 << h);
+            let parent = branching(
+                self.nodes[left as usize], 
+                self.nodes[pos as usize],
+            );
+            self.nodes.push(parent);
+            pos += 1;
+            h += 1;
+        }
+
+        self.leaves += 1;
+        leaf_pos
+    }
+
+    /// Current root, or `None` if the range is empty.
+    pub fn root(&self) -> Option<[u8; 32]> {
+        self.root_at(self.size())
+    }
+
+    /// Root the range had when it held `mmr_size` nodes.
+    ///
+    /// # Returns
+    /// `None` if `mmr_size` is 0, not a valid MMR size, or larger than the
+    /// range.
+    pub fn root_at(&self, mmr_size: u64) -> Option<[u8; 32]> {
+        if mmr_size > self.size() {
+            return None;
+        }
+        let positions = peak_positions(mmr_size)?;
+
+        let mut peaks: Vec<[u8; 32]> = Vec::with_capacity(positions.len());
+        for pos in positions {
+            peaks.push(self.nodes[pos as usize]);
+        }
+        bag_peaks(&peaks)
+    }
+
+    /// Inclusion proof for leaf `leaf_index` against the range at
+    /// `mmr_size` nodes.
+    ///
+    /// # Returns
+    /// `None` if `mmr_size` is invalid or larger than the range, or if the
+    /// leaf was not yet appended at that size.
+    pub fn proof(&self, leaf_index: u64, mmr_size: u64) -> Option<MmrProof> {
+        if mmr_size > self.size() {
+            return None;
+        }
+        let positions = peak_positions(mmr_size)?;
+        let mut pos = leaf_index_to_pos(leaf_index)?;
+        if pos >= mmr_size {
+            return None;
+        }
+
+        // Climb to the peak of the mountain holding the leaf.
+        let mut path: Vec<[u8; 32]> = Vec::new();
+        let mut h = 0;
+        while !positions.contains(&pos) {
+            if pos_height(pos + 1) > h {
+                // Right child: sibling on the left, parent right after.
+                path.push(self.nodes[(pos + 1 - (2 << h)) as usize]);
+                pos += 1;
+            } else {
+                // Left child: sibling is the right subtree.
+                path.push(self.nodes[(pos + (2 << h) - 1) as usize]);
+                pos += 2 << h;
+            }
+            h += 1;
+        }
+
+        let mut peaks: Vec<[u8; 32]> = Vec::new();
+        for peak in positions {
+            if peak != pos {
+                peaks.push(self.nodes[peak as usize]);
+            }
+        }
+
+        Some(MmrProof { mmr_size, path, peaks })
+    }
+}
+
+/// Verify an MMR inclusion proof.
+///
+/// # Arguments
+/// - `root` - root of the range at `proof.mmr_size`.
+/// - `leaf` - the raw leaf (hashed with `sha256` like in `Mmr::append`).
+/// - `leaf_index` - index of the leaf in append order.
+/// - `proof` - proof produced by `Mmr::proof`.
+///
+/// # Description
+/// - Hash up `path`, using the height of each position to tell left from
+///   right children, and require to end exactly on a peak of `mmr_size`.
+///   A path that goes on past a peak is rejected before climbing out of
+///   the range, so positions never overflow, whatever `mmr_size` and
+///   `leaf_index` are.
+/// - Put the recomputed peak back among `proof.peaks` and bag them.
+///
+/// # Returns
+/// `true` if the leaf is at `leaf_index` in the range of that size.
+pub fn verify_mmr_proof(
+    root: [u8; 32],
+    leaf: &[u8],
+    leaf_index: u64,
+    proof: &MmrProof,
+) -> bool {
+    let positions = match peak_positions(proof.mmr_size) {
+        Some(positions) => positions,
+        None => return false,
+    };
+    let mut pos = match leaf_index_to_pos(leaf_index) {
+        Some(pos) => pos,
+        None => return false,
+    };
+    if pos >= proof.mmr_size 
+        || proof.peaks.len() + 1 != positions.len() 
+        || proof.path.len() >= 64 
+    {
+        return false;
+    }
+
+    let mut node = sha256(leaf);
+    let mut h = 0;
+    while h < proof.path.len() {
+        // Below a peak the parent is still inside the range.
+        if positions.contains(&pos) {
+            return false;
+        }
+        if pos_height(pos + 1) > h as u32 {
+            node = branching(proof.path[h], node);
+            pos += 1;
+        } else {
+            node = branching(node, proof.path[h]);
+            pos += 2 << h;
+        }
+        h += 1;
+    }
+
+    // The path must end on a peak; rebuild the full peak list around it.
+    let mut peaks: Vec<[u8; 32]> = Vec::with_capacity(positions.len());
+    let mut found = false;
+    let mut k = 0;
+    for peak in positions {
+        if peak == pos {
+            peaks.push(node);
+            found = true;
+        } else {
+            if k == proof.peaks.len() {
+                return false;
+            }
+            peaks.push(proof.peaks[k]);
+            k += 1;
+        }
+    }
+
+    found && bag_peaks(&peaks) == Some(root)
+}
+
+/// Height of the node at position `pos` (0 for leaves).
+///
+/// # Description
+/// In 1-based post-order numbering, the leftmost node of each height h is
+/// `2^(h+1) - 1` (all ones). Jump left to the same height in the leftmost
+/// mountain until the position is all ones, then read the height off its
+/// bit length.
+///
+/// # Panics
+/// If `pos` is `u64::MAX`, in release builds too. The largest range has
+/// `u64::MAX` nodes, so its last position is `u64::MAX - 1`.
+pub fn pos_height(pos: u64) -> u32 {
+    assert!(pos != u64::MAX, "no MMR position is u64::MAX");
+    let mut p = pos + 1;
+    // All ones: the bit length equals the number of set bits.
+    while p.count_ones() != 64 - p.leading_zeros() {
+        let bit_len = 64 - p.leading_zeros();
+        p -= (1 << (bit_len - 1)) - 1;
+    }
+    63 - p.leading_zeros()
+}
+
+/// Position of the leaf appended at `leaf_index`.
+///
+/// Each earlier leaf adds one node, plus one parent per completed pair:
+/// `2 * index - popcount(index)`, computed as
+/// `index + (index - popcount(index))` so it only fails when the result
+/// itself does not fit.
+///
+/// # Returns
+/// The position, or `None` if it overflows `u64`.
+pub fn leaf_index_to_pos(leaf_index: u64) -> Option<u64> {
+    leaf_index.checked_add(leaf_index - leaf_index.count_ones() as u64)
+}
+
+/// Number of nodes of a range holding `leaf_count` leaves.
+///
+/// # Returns
+/// The size, or `None` if it overflows `u64`.
+pub fn mmr_size(leaf_count: u64) -> Option<u64> {
+    leaf_count.checked_add(leaf_count - leaf_count.count_ones() as u64)
+}
+
+/// Positions of the peaks of a range of `mmr_size` nodes, left to right.
+///
+/// # Description
+/// Cut off the largest perfect tree (2^(h+1) - 1 nodes) that still fits,
+/// then repeat on the rest. The tree size is built as `h + 1` low bits set,
+/// so h = 63 (a range of `u64::MAX` nodes) does not overflow.
+///
+/// # Returns
+/// `None` if `mmr_size` is 0 or no range has exactly that many nodes.
+pub fn peak_positions(mmr_size: u64) -> Option<Vec<u64>> {
+    if mmr_size == 0 {
+        return None;
+    }
+
+    let mut peaks: Vec<u64> = Vec::new();
+    let mut offset = 0u64;
+    let mut h = 63 - mmr_size.leading_zeros();
+    loop {
+        let tree_size = u64::MAX >> (63 - h);
+        if mmr_size - offset >= tree_size {
+            offset += tree_size;
+            peaks.push(offset - 1);
+        }
+        if h == 0 {
+            break;
+        }
+        h -= 1;
+    }
+
+    if offset == mmr_size {
+        Some(peaks)
+    } else {
+        None
+    }
+}
+
+/// Bag the peaks right to left into a single root.
+fn bag_peaks(peaks: &[[u8; 32]]) -> Option<[u8; 32]> {
+    if peaks.is_empty() {
+        return None;
+    }
+
+    let mut root = peaks[peaks.len() - 1];
+    let mut i = peaks.len() - 1;
+    while i > 0 {
+        i -= 1;
+        root = branching(peaks[i], root);
+    }
+    Some(root)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::merkle_tree::merkle_tree;
+
+    fn mmr_with(n: u64) -> Mmr {
+        let mut mmr = Mmr::new();
+        for i in 0..n {
+            mmr.append(&i.to_be_bytes());
+        }
+        mmr
+    }
+
+    #[test]
+    fn position_and_height_helpers() {
+        let mut heights = Vec::new();
+        for pos in 0..11 {
+            heights.push(pos_height(pos));
+        }
+        let mut positions = Vec::new();
+        for i in 0..6 {
+            positions.push(leaf_index_to_pos(i).unwrap());
+        }
+
+        assert_eq!((heights), (vec![0, 0, 1, 0, 0, 1, 2, 0, 0, 1, 0]));
+        assert_eq!((positions), (vec![0, 1, 3, 4, 7, 8]));
+        assert_eq!((mmr_size(5)), (Some(8)));
+        assert_eq!((mmr_size(8)), (Some(15)));
+    }
+
+    #[test]
+    fn peak_positions_follow_the_bits_of_the_leaf_count() {
+        assert_eq!((peak_positions(1)), (Some(vec![0])));
+        assert_eq!((peak_positions(4)), (Some(vec![2, 3])));
+        assert_eq!((peak_positions(11)), (Some(vec![6, 9, 10])));
+        assert_eq!((peak_positions(2)), (None));
+        assert_eq!((peak_positions(0)), (None));
+    }
+
+    #[test]
+    fn helpers_do_not_overflow_near_u64_max() {
+        assert_eq!((leaf_index_to_pos(1 << 63)), (Some(u64::MAX)));
+        assert_eq!((leaf_index_to_pos((1 << 63) + 1)), (None));
+        assert_eq!((leaf_index_to_pos(u64::MAX)), (None));
+        assert_eq!((mmr_size(1 << 63)), (Some(u64::MAX)));
+        assert_eq!((mmr_size(u64::MAX)), (None));
+        assert_eq!((peak_positions(u64::MAX)), (Some(vec![u64::MAX - 1])));
+        assert_eq!((peak_positions(u64::MAX - 1)), (None));
+        assert_eq!((pos_height(u64::MAX - 1)), (63));
+    }
+
+    #[test]
+    #[should_panic]
+    fn pos_height_rejects_u64_max() {
+        pos_height(u64::MAX);
+    }
+
+    #[test]
+    fn append_stores_parents_in_post_order() {
+        let mmr = mmr_with(3);
+        let h0 = sha256(&0u64.to_be_bytes());
+        let h1 = sha256(&1u64.to_be_bytes());
+        let h2 = sha256(&2u64.to_be_bytes());
+
+        assert_eq!((mmr.size()), (4));
+        assert_eq!((mmr.node(2)), (Some(branching(h0, h1))));
+        assert_eq!((mmr.root()), (Some(branching(branching(h0, h1), h2))));
+    }
+
+    #[test]
+    fn size_matches_helper_after_every_append() {
+        let mut mmr = Mmr::new();
+        assert_eq!((mmr.root()), (None));
+        for i in 0..100u64 {
+            let pos = mmr.append(&i.to_be_bytes());
+
+            assert_eq!((Some(pos)), (leaf_index_to_pos(i)));
+            assert_eq!((Some(mmr.size())), (mmr_size(i + 1)));
+        }
+    }
+
+    #[test]
+    fn power_of_two_root_matches_merkle_tree() {
+        // A single mountain is a plain Merkle tree of the hashed leaves.
+        let mut data: Vec<[u8; 32]> = Vec::new();
+        let mut mmr = Mmr::new();
+        for i in 0..16u8 {
+            data.push([i; 32]);
+            mmr.append(&[i; 32]);
+        }
+
+        assert_eq!((mmr.root()), (Some(merkle_tree(data))));
+    }
+
+    #[test]
+    fn every_proof_verifies_at_every_size() {
+        let mmr = mmr_with(40);
+        for n in 1..=40u64 {
+            let size = mmr_size(n).unwrap();
+            let root = mmr.root_at(size).unwrap();
+            for i in 0..n {
+                let proof = mmr.proof(i, size).unwrap();
+
+                assert!(verify_mmr_proof(root, &i.to_be_bytes(), i, &proof));
+            }
+        }
+    }
+
+    #[test]
+    fn historic_proofs_still_verify_after_appends() {
+        let mut mmr = mmr_with(11);
+        let size = mmr.size();
+        let root = mmr.root().unwrap();
+        let proof = mmr.proof(6, size).unwrap();
+
+        for i in 11..50u64 {
+            mmr.append(&i.to_be_bytes());
+        }
+
+        assert_eq!((mmr.root_at(size)), (Some(root)));
+        assert_eq!((mmr.proof(6, size)), (Some(proof.clone())));
+        assert!(verify_mmr_proof(root, &6u64.to_be_bytes(), 6, &proof));
+        assert_ne!((mmr.root()), (Some(root)));
+    }
+
+    #[test]
+    fn rejects_wrong_leaf_index_or_tampered_proof() {
+        let mmr = mmr_with(13);
+        let root = mmr.root().unwrap();
+        let proof = mmr.proof(9, mmr.size()).unwrap();
+
+        assert!(!verify_mmr_proof(root, &8u64.to_be_bytes(), 9, &proof));
+        assert!(!verify_mmr_proof(root, &9u64.to_be_bytes(), 8, &proof));
+
+        let mut bad = proof.clone();
+        bad.path[0][0] ^= 0x01;
+        assert!(!verify_mmr_proof(root, &9u64.to_be_bytes(), 9, &bad));
+
+        let mut bad = proof.clone();
+        bad.peaks.pop();
+        assert!(!verify_mmr_proof(root, &9u64.to_be_bytes(), 9, &bad));
+
+        let mut bad = proof.clone();
+        bad.path.pop();
+        assert!(!verify_mmr_proof(root, &9u64.to_be_bytes(), 9, &bad));
+
+        let mut bad = proof.clone();
+        bad.path.push([0u8; 32]);
+        assert!(!verify_mmr_proof(root, &9u64.to_be_bytes(), 9, &bad));
+    }
+
+    #[test]
+    fn no_proof_for_invalid_or_future_sizes() {
+        let mmr = mmr_with(5);
+
+        assert_eq!((mmr.proof(0, 2)), (None));
+        assert_eq!((mmr.proof(0, 15)), (None));
+        assert_eq!((mmr.proof(4, 7)), (None));
+        assert_eq!((mmr.root_at(5)), (None));
+    }
+
+    #[test]
+    fn rejects_untrusted_sizes_and_indices_near_u64_max() {
+        let mmr = mmr_with(13);
+        let root = mmr.root().unwrap();
+        let proof = mmr.proof(9, mmr.size()).unwrap();
+        let leaf = 9u64.to_be_bytes();
+
+        let mut bad = proof.clone();
+        bad.mmr_size = u64::MAX;
+        assert!(!verify_mmr_proof(root, &leaf, 9, &bad));
+        assert!(!verify_mmr_proof(root, &leaf, u64::MAX, &proof));
+
+        // Last leaf of a range of u64::MAX nodes, climbing 63 levels to the
+        // only peak at u64::MAX - 1.
+        let mut tall = MmrProof {
+            mmr_size: u64::MAX,
+            path: vec![[0u8; 32]; 63],
+            peaks: Vec::new(),
+        };
+        assert!(!verify_mmr_proof(root, &leaf, (1 << 63) - 1, &tall));
+        tall.path.pop();
+        assert!(!verify_mmr_proof(root, &leaf, (1 << 63) - 1, &tall));
+    }
+}
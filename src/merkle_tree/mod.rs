@@ -1,6 +1,41 @@
+//! Merkle trees and proofs.
+//!
+//! Only `merkle_tree_with` (with `leaf_loading_with` and `branching_with`)
+//! is generic over the `HashFunction`, so it can build e.g. a SHA-512 tree.
+//! Everything else works on 32-byte SHA-256 nodes: `MerkleTree` and its 
+//! proofs and multiproofs, `MerkleAccumulator`, consistency proofs, `Mmr`,
+//! `SparseMerkleTree` and the parallel builder.
+
 pub mod merkle;
 pub mod loading;
 pub mod branch;
+pub mod mode;
+pub mod bitcoin;
+pub mod tree;
+pub mod proof;
+pub mod multiproof;
+pub mod accumulator;
+pub mod consistency;
+pub mod sparse;
+pub mod parallel;
+pub mod mmr;
 
-use loading::leaf_loading;
-use branch::branching; 
\ No newline at end of file
+pub use loading::{leaf_loading, leaf_loading_with};
+pub use branch::{branching, branching_with}; 
+pub use merkle::{merkle_tree, merkle_tree_with};
+pub use mode::MerkleMode;
+pub use bitcoin::{bitcoin_merkle_root, reverse_order, sha256d};
+pub use tree::MerkleTree;
+pub use proof::{verify_proof, verify_proof_from_node, verify_proof_with, MerkleProof};
+pub use multiproof::{
+    verify_multiproof, verify_multiproof_from_nodes, verify_multiproof_with, 
+    MerkleMultiProof,
+};
+pub use accumulator::MerkleAccumulator;
+pub use consistency::{verify_consistency, ConsistencyProof};
+pub use sparse::{verify_sparse_proof, SparseMerkleTree, SparseProof, SPARSE_DEPTH};
+pub use parallel::{merkle_tree_parallel, parallel_merkle_root};
+pub use mmr::{
+    leaf_index_to_pos, mmr_size, peak_positions, pos_height, verify_mmr_proof, Mmr, 
+    MmrProof,
+};
@@ -0,0 +1,157 @@
+use crate::merkle_tree::branching;
+use crate::merkle_tree::bitcoin::sha256d;
+use crate::sha256::{sha256, Sha256};
+
+/// How leaves and interior nodes are hashed, and how unpaired nodes are 
+/// handled.
+///
+/// # Variants
+/// - `DuplicateLast` (default): Bitcoin-style tree as built by `merkle_tree`.
+///   Leaves are hashed with SHA-256, parents are `branching(left, right)`, 
+///   and the last node of an odd level is paired with itself (a single leaf
+///   too). Two different leaf lists can share a root (CVE-2012-2459), and an
+///   inner node can be passed off as a leaf.
+/// - `Rfc6962`: Certificate Transparency tree. Leaves are hashed as 
+///   `SHA-256(0x00 || leaf)`, parents as `SHA-256(0x01 || left || right)`,
+///   and the last node of an odd level is promoted unchanged.
+/// - `Bitcoin`: block merkle root. Leaves are txids in internal byte order 
+///   and are used as-is, parents are `SHA-256d(left || right)`, and the last
+///   node of an odd level is paired with itself. A block with a single 
+///   transaction has its txid as root.
+///
+/// # References
+/// - [RFC 6962, section 2.1](https://www.rfc-editor.org/rfc/rfc6962#section-2.1)
+/// - [Bitcoin developer guide, merkle trees](https://developer.bitcoin.org/reference/block_chain.html#merkle-trees)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MerkleMode {
+    #[default]
+    DuplicateLast,
+    Rfc6962,
+    Bitcoin,
+}
+
+impl MerkleMode {
+    /// Hash a leaf into a leaf node.
+    ///
+    /// In `Bitcoin` mode the leaf is already a txid and is used as-is.
+    ///
+    /// # Returns
+    /// The leaf node, or `None` in `Bitcoin` mode if `leaf` is not 32 bytes
+    /// long. Leaves of any length are accepted in the other modes.
+    pub fn hash_leaf(self, leaf: &[u8]) -> Option<[u8; 32]> {
+        match self {
+            MerkleMode::DuplicateLast => Some(sha256(leaf)),
+            MerkleMode::Rfc6962 => {
+                let mut hasher = Sha256::new();
+                hasher.update(&[0x00]);
+                hasher.update(leaf);
+                Some(hasher.finalize())
+            }
+            MerkleMode::Bitcoin => leaf.try_into().ok(),
+        }
+    }
+
+    /// Combine a left and right child into their parent node.
+    pub fn hash_node(self, left: [u8; 32], right: [u8; 32]) -> [u8; 32] {
+        match self {
+            MerkleMode::DuplicateLast => branching(left, right),
+            MerkleMode::Rfc6962 => {
+                let mut hasher = Sha256::new();
+                hasher.update(&[0x01]);
+                hasher.update(&left);
+                hasher.update(&right);
+                hasher.finalize()
+            }
+            MerkleMode::Bitcoin => {
+                let mut concat = [0u8; 64];
+                concat[..32].copy_from_slice(&left);
+                concat[32..].copy_from_slice(&right);
+                sha256d(&concat)
+            }
+        }
+    }
+
+    /// Parent of the unpaired last node of an odd level.
+    pub(crate) fn hash_unpaired(self, node: [u8; 32]) -> [u8; 32] {
+        match self {
+            MerkleMode::DuplicateLast | MerkleMode::Bitcoin => {
+                self.hash_node(node, node)
+            }
+            MerkleMode::Rfc6962 => node,
+        }
+    }
+
+    /// Whether the unpaired last node of an odd level is promoted unchanged
+    /// (instead of paired with itself).
+    pub(crate) fn promotes_unpaired(self) -> bool {
+        self == MerkleMode::Rfc6962
+    }
+
+    /// Whether a lone leaf is still combined into a parent above it.
+    pub(crate) fn pairs_single_leaf(self) -> bool {
+        match self {
+            MerkleMode::DuplicateLast => true,
+            MerkleMode::Rfc6962 | MerkleMode::Bitcoin => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn duplicate_last_matches_leaf_loading_and_branching() {
+        let a = [0x19u8; 32];
+        let b = [0xf2u8; 32];
+        let mode = MerkleMode::DuplicateLast;
+
+        assert_eq!((mode.hash_leaf(&a)), (Some(sha256(&a))));
+        assert_eq!((mode.hash_node(a, b)), (branching(a, b)));
+        assert_eq!((mode.hash_unpaired(a)), (branching(a, a)));
+    }
+
+    #[test]
+    fn rfc6962_prefixes_leaves_and_nodes() {
+        let a = [0x19u8; 32];
+        let b = [0xf2u8; 32];
+        let mode = MerkleMode::Rfc6962;
+
+        assert_eq!((mode.hash_leaf(&a)), (Some(sha256(&[&[0x00][..], &a].concat()))));
+        assert_eq!(
+            (mode.hash_node(a, b)), 
+            (sha256(&[&[0x01][..], &a, &b].concat()))
+        );
+        assert_eq!((mode.hash_unpaired(a)), (a));
+    }
+
+    #[test]
+    fn rfc6962_empty_leaf_hash_matches_ct_reference() {
+        // Leaf hash of the empty string from the CT reference data.
+        let result = MerkleMode::Rfc6962.hash_leaf(b"").unwrap();
+        let expected = [
+            0x6e, 0x34, 0x0b, 0x9c, 0xff, 0xb3, 0x7a, 0x98,
+            0x9c, 0xa5, 0x44, 0xe6, 0xbb, 0x78, 0x0a, 0x2c,
+            0x78, 0x90, 0x1d, 0x3f, 0xb3, 0x37, 0x38, 0x76,
+            0x85, 0x11, 0xa3, 0x06, 0x17, 0xaf, 0xa0, 0x1d,
+        ];
+
+        assert_eq!((result), (expected));
+    }
+
+    #[test]
+    fn bitcoin_uses_txids_as_is_and_double_hashes_nodes() {
+        let a = [0x19u8; 32];
+        let b = [0xf2u8; 32];
+        let mode = MerkleMode::Bitcoin;
+
+        assert_eq!((mode.hash_leaf(&a)), (Some(a)));
+        assert_eq!((mode.hash_leaf(&a[..31])), (None));
+        assert_eq!((mode.hash_leaf(&[0x19u8; 33])), (None));
+        assert_eq!(
+            (mode.hash_node(a, b)), 
+            (sha256(&sha256(&[&a[..], &b].concat())))
+        );
+        assert_eq!((mode.hash_unpaired(a)), (mode.hash_node(a, a)));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,352 @@
+use crate::merkle_tree::{MerkleMode, MerkleTree};
+
+/// Inclusion proof for several leaves of a `MerkleTree` at once.
+///
+/// # Description
+/// - `nodes` holds every hash the verifier cannot compute by itself, level 
+///   by level from the leaves up, and left to right within a level.
+/// - A node is left out when it is the sibling of another proven node, or 
+///   when the path node is the unpaired last node of an odd level (it is 
+///   paired with itself or promoted, depending on the tree's `MerkleMode`).
+///   Shared upper nodes are therefore emitted once.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MerkleMultiProof {
+    pub leaf_count: usize,
+    pub nodes: Vec<[u8; 32]>,
+}
+
+impl MerkleTree {
+    /// Multiproof for the leaves at `indices` (any order, duplicates allowed).
+    ///
+    /// # Returns
+    /// `None` if `indices` is empty or contains an index out of range.
+    pub fn multiproof(&self, indices: &[usize]) -> Option<MerkleMultiProof> {
+        let mut known = sorted_unique(indices);
+        if known.is_empty() || known[known.len() - 1] >= self.len() {
+            return None;
+        }
+
+        let mut nodes: Vec<[u8; 32]> = Vec::new();
+        let mut n = 0;
+        while n + 1 < self.depth() {
+            let level = self.level(n).unwrap();
+            let mut parents: Vec<usize> = Vec::new();
+            let mut j = 0;
+            while j < known.len() {
+                let i = known[j];
+                if i.is_multiple_of(2) {
+                    if j + 1 < known.len() && known[j + 1] == i + 1 {
+                        // Both children are known.
+                        j += 1;
+                    } else if i + 1 < level.len() {
+                        nodes.push(level[i + 1]);
+                    }
+                    // Else: unpaired last node, duplicated by the verifier.
+                } else {
+                    nodes.push(level[i - 1]);
+                }
+                parents.push(i / 2);
+                j += 1;
+            }
+            known = parents;
+            n += 1;
+        }
+
+        Some(MerkleMultiProof { leaf_count: self.len(), nodes })
+    }
+}
+
+/// Verify a multiproof for a `DuplicateLast` tree without the full tree.
+///
+/// # Arguments
+/// - `root` - the Merkle root the leaves should belong to.
+/// - `leaves` - `(raw leaf record, index)` pairs, in any order.
+/// - `proof` - proof produced by `MerkleTree::multiproof` for those indices.
+///
+/// # Description
+/// - Hash the leaves, then rebuild each level from the known nodes, taking 
+///   missing siblings from `proof.nodes` in order and pairing an unpaired 
+///   last node with itself.
+/// - Every proof node must be consumed, and the rebuilt root must match.
+///
+/// # Returns
+/// `true` if all leaves are included at their indices under `root`.
+pub fn verify_multiproof(
+    root: [u8; 32], 
+    leaves: &[(&[u8], usize)],
+    proof: &MerkleMultiProof,
+) -> bool {
+    verify_multiproof_with(MerkleMode::DuplicateLast, root, leaves, proof)
+}
+
+/// Verify a multiproof for a tree built in `mode`.
+///
+/// # Description
+/// Same as `verify_multiproof`; in `Rfc6962` mode an unpaired last node is 
+/// promoted instead of duplicated.
+///
+/// # Returns
+/// `true` if all leaves are included at their indices under `root`; `false`
+/// as well if a record cannot be hashed in `mode` (not 32 bytes in 
+/// `Bitcoin` mode).
+pub fn verify_multiproof_with(
+    mode: MerkleMode,
+    root: [u8; 32], 
+    leaves: &[(&[u8], usize)],
+    proof: &MerkleMultiProof,
+) -> bool {
+    let mut nodes: Vec<([u8; 32], usize)> = Vec::with_capacity(leaves.len());
+    for (leaf, index) in leaves {
+        match mode.hash_leaf(leaf) {
+            Some(node) => nodes.push((node, *index)),
+            None => return false,
+        }
+    }
+
+    verify_multiproof_from_nodes(mode, root, &nodes, proof)
+}
+
+/// Verify a multiproof starting from already hashed leaf nodes.
+///
+/// # Arguments
+/// - `nodes` - `(leaf node, index)` pairs, in any order, e.g. nodes a tree 
+///   was built from with `MerkleTree::from_hashed`.
+///
+/// # Description
+/// Same as `verify_multiproof_with`, without hashing the leaves first.
+pub fn verify_multiproof_from_nodes(
+    mode: MerkleMode,
+    root: [u8; 32], 
+    nodes: &[([u8; 32], usize)],
+    proof: &MerkleMultiProof,
+) -> bool {
+    // Sort the leaf nodes by index.
+    let mut known: Vec<(usize, [u8; 32])> = Vec::new();
+    for (node, index) in nodes {
+        known.push((*index, *node));
+    }
+    known.sort_by_key(|node| node.0);
+
+    let mut j = 1;
+    while j < known.len() {
+        if known[j].0 == known[j - 1].0 {
+            // The same index twice must carry the same leaf.
+            if known[j].1 != known[j - 1].1 {
+                return false;
+            }
+            known.remove(j);
+        } else {
+            j += 1;
+        }
+    }
+    if known.is_empty() || known[known.len() - 1].0 >= proof.leaf_count {
+        return false;
+    }
+
+    let mut next = 0;
+    let mut width = proof.leaf_count;
+    let mut first = true;
+    while width > 1 || (first && mode.pairs_single_leaf()) {
+        let mut parents: Vec<(usize, [u8; 32])> = Vec::new();
+        let mut j = 0;
+        while j < known.len() {
+            let (i, node) = known[j];
+            let parent = if i % 2 == 0 {
+                if j + 1 < known.len() && known[j + 1].0 == i + 1 {
+                    j += 1;
+                    mode.hash_node(node, known[j].1)
+                } else if i + 1 < width {
+                    if next >= proof.nodes.len() {
+                        return false;
+                    }
+                    next += 1;
+                    mode.hash_node(node, proof.nodes[next - 1])
+                } else {
+                    // Unpaired last node, duplicate or promote it.
+                    mode.hash_unpaired(node)
+                }
+            } else {
+                if next >= proof.nodes.len() {
+                    return false;
+                }
+                next += 1;
+                mode.hash_node(proof.nodes[next - 1], node)
+            };
+            parents.push((i / 2, parent));
+            j += 1;
+        }
+        known = parents;
+        width = width.div_ceil(2);
+        first = false;
+    }
+
+    next == proof.nodes.len() && known[0].1 == root
+}
+
+/// Sort indices ascending and drop duplicates.
+fn sorted_unique(indices: &[usize]) -> Vec<usize> {
+    let mut out = indices.to_vec();
+    out.sort();
+    out.dedup();
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::leaves;
+
+    fn pick<'a>(data: &'a [[u8; 32]], indices: &[usize]) -> Vec<(&'a [u8], usize)> {
+        let mut out: Vec<(&[u8], usize)> = Vec::new();
+        for i in indices {
+            out.push((&data[*i], *i));
+        }
+        out
+    }
+
+    #[test]
+    fn adjacent_leaves_need_no_sibling_at_the_leaf_level() {
+        let data = leaves(8);
+        let tree = MerkleTree::new(&data).unwrap();
+
+        let proof = tree.multiproof(&[2, 3]).unwrap();
+
+        // Only the level-1 and level-2 siblings are needed.
+        assert_eq!((proof.nodes.len()), (2));
+        assert!(verify_multiproof(tree.root(), &pick(&data, &[2, 3]), &proof));
+    }
+
+    #[test]
+    fn leaves_in_different_subtrees_share_upper_nodes() {
+        let data = leaves(8);
+        let tree = MerkleTree::new(&data).unwrap();
+
+        let proof = tree.multiproof(&[1, 6]).unwrap();
+
+        // Two leaf siblings and two level-1 siblings, the root halves are 
+        // both computed.
+        assert_eq!((proof.nodes.len()), (4));
+        assert!(verify_multiproof(tree.root(), &pick(&data, &[6, 1]), &proof));
+    }
+
+    #[test]
+    fn duplicated_last_node_is_not_emitted() {
+        let data = leaves(7);
+        let tree = MerkleTree::new(&data).unwrap();
+
+        let proof = tree.multiproof(&[6]).unwrap();
+        let single = tree.proof(6).unwrap();
+
+        assert_eq!((proof.nodes.len()), (single.siblings.len() - 1));
+        assert!(verify_multiproof(tree.root(), &pick(&data, &[6]), &proof));
+    }
+
+    #[test]
+    fn every_subset_verifies_for_small_trees() {
+        for n in 1..=9 {
+            let data = leaves(n);
+            let tree = MerkleTree::new(&data).unwrap();
+            for mask in 1..(1u32 << n) {
+                let mut indices = Vec::new();
+                for i in 0..n {
+                    if mask & (1 << i) != 0 {
+                        indices.push(i);
+                    }
+                }
+                let proof = tree.multiproof(&indices).unwrap();
+
+                let proven = pick(&data, &indices);
+
+                assert!(verify_multiproof(tree.root(), &proven, &proof));
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_wrong_leaf_extra_node_or_missing_node() {
+        let data = leaves(10);
+        let tree = MerkleTree::new(&data).unwrap();
+        let proof = tree.multiproof(&[0, 5, 9]).unwrap();
+
+        let proven = pick(&data, &[0, 5, 9]);
+
+        let mut leaf = data[5];
+        leaf[0] ^= 0x01;
+        let mut wrong = proven.clone();
+        wrong[1].0 = &leaf;
+        assert!(!verify_multiproof(tree.root(), &wrong, &proof));
+
+        let mut extra = proof.clone();
+        extra.nodes.push([0u8; 32]);
+        assert!(!verify_multiproof(tree.root(), &proven, &extra));
+
+        let mut missing = proof.clone();
+        missing.nodes.pop();
+        assert!(!verify_multiproof(tree.root(), &proven, &missing));
+    }
+
+    #[test]
+    fn rejects_empty_or_out_of_range_indices() {
+        let tree = MerkleTree::new(&leaves(4)).unwrap();
+
+        assert_eq!((tree.multiproof(&[])), (None));
+        assert_eq!((tree.multiproof(&[1, 4])), (None));
+    }
+
+    #[test]
+    fn rfc6962_multiproofs_verify_with_promotion() {
+        for n in 1..=9 {
+            let data = leaves(n);
+            let tree = MerkleTree::with_mode(&data, MerkleMode::Rfc6962).unwrap();
+            for mask in 1..(1u32 << n) {
+                let mut indices = Vec::new();
+                for i in 0..n {
+                    if mask & (1 << i) != 0 {
+                        indices.push(i);
+                    }
+                }
+                let proof = tree.multiproof(&indices).unwrap();
+                let proven = pick(&data, &indices);
+
+                assert!(verify_multiproof_with(
+                    MerkleMode::Rfc6962, tree.root(), &proven, &proof
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn multiproofs_verify_for_variable_length_records() {
+        let mut records: Vec<Vec<u8>> = Vec::new();
+        for i in 0..7usize {
+            records.push(vec![0x40 + i as u8; i * 9 + 1]);
+        }
+        let mode = MerkleMode::Rfc6962;
+        let tree = MerkleTree::from_data_iter(&records, mode).unwrap();
+
+        let proof = tree.multiproof(&[1, 4, 6]).unwrap();
+        let proven: Vec<(&[u8], usize)> = vec![
+            (&records[4], 4), (&records[1], 1), (&records[6], 6),
+        ];
+
+        assert!(verify_multiproof_with(mode, tree.root(), &proven, &proof));
+    }
+
+    #[test]
+    fn multiproofs_verify_from_pre_hashed_leaf_nodes() {
+        let mut nodes: Vec<[u8; 32]> = Vec::new();
+        for leaf in &leaves(10) {
+            nodes.push(MerkleMode::Rfc6962.hash_leaf(leaf).unwrap());
+        }
+        let mode = MerkleMode::Rfc6962;
+        let tree = MerkleTree::from_hashed(nodes.clone(), mode).unwrap();
+
+        let proof = tree.multiproof(&[0, 3, 9]).unwrap();
+        let known = vec![(nodes[9], 9), (nodes[0], 0), (nodes[3], 3)];
+
+        assert!(verify_multiproof_from_nodes(mode, tree.root(), &known, &proof));
+        // As raw records the nodes would be hashed a second time.
+        let raw = pick(&nodes, &[0, 3, 9]);
+        assert!(!verify_multiproof_with(mode, tree.root(), &raw, &proof));
+    }
+}
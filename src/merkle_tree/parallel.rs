@@ -0,0 +1,182 @@
+use std::thread;
+
+use crate::merkle_tree::tree::parent_level;
+use crate::merkle_tree::MerkleMode;
+
+/// Merkle root computed across all available cores.
+///
+/// # Arguments
+/// - `leaf` - A vector of 32-byte arrays, each representing a transaction.
+///
+/// # Description
+/// Same root as `merkle_tree`, built with `parallel_merkle_root` in
+/// `DuplicateLast` mode using `std::thread::available_parallelism` threads.
+///
+/// # Returns
+/// - `[u8; 32]` - the Merkle root of the tree. Panics on an empty vector,
+///   which has no root.
+pub fn merkle_tree_parallel(leaf: Vec<[u8; 32]>) -> [u8; 32] {
+    let threads = match thread::available_parallelism() {
+        Ok(n) => n.get(),
+        Err(_) => 1,
+    };
+    parallel_merkle_root(&leaf, MerkleMode::DuplicateLast, threads)
+        .expect("merkle tree needs at least one leaf")
+}
+
+/// Merkle root of `leaves` in `mode`, spread over up to `threads` threads.
+///
+/// # Arguments
+/// - `leaves` - raw 32-byte leaves.
+/// - `mode` - how leaves and nodes are hashed.
+/// - `threads` - maximum number of worker threads.
+///
+/// # Description
+/// - Split the leaves into chunks of 2^k leaves, with k the smallest value
+///   giving at most `threads` chunks.
+/// - Each scoped thread hashes its chunk and reduces it k levels to a
+///   single subtree root. Chunks start at multiples of 2^k, so pairs never
+///   straddle two chunks, and only the last chunk can hold the unpaired
+///   last node of a level; it is handled exactly like the sequential tree.
+/// - Reduce the subtree roots sequentially to the root.
+/// - With fewer than two chunks, build sequentially.
+///
+/// # Returns
+/// The root, identical to `MerkleTree::with_mode(leaves, mode)`, or `None`
+/// for an empty slice.
+pub fn parallel_merkle_root(
+    leaves: &[[u8; 32]],
+    mode: MerkleMode,
+    threads: usize,
+) -> Option<[u8; 32]> {
+    if leaves.is_empty() {
+        return None;
+    }
+
+    // Smallest power-of-two chunk giving at most `threads` chunks.
+    let mut chunk = 1;
+    let mut k = 0;
+    while leaves.len().div_ceil(chunk) > threads.max(1) {
+        chunk *= 2;
+        k += 1;
+    }
+    if leaves.len().div_ceil(chunk) < 2 {
+        return Some(reduce_to_root(hash_leaves(leaves, mode), mode, true));
+    }
+
+    let roots: Vec<[u8; 32]> = thread::scope(|s| {
+        let mut handles = Vec::new();
+        let mut start = 0;
+        while start < leaves.len() {
+            let end = (start + chunk).min(leaves.len());
+            let part = &leaves[start..end];
+            handles.push(s.spawn(move || {
+                reduce_chunk(hash_leaves(part, mode), mode, k)
+            }));
+            start = end;
+        }
+
+        let mut roots = Vec::with_capacity(handles.len());
+        for handle in handles {
+            roots.push(handle.join().unwrap());
+        }
+        roots
+    });
+
+    Some(reduce_to_root(roots, mode, false))
+}
+
+/// Hash every leaf with `mode.hash_leaf`.
+fn hash_leaves(leaves: &[[u8; 32]], mode: MerkleMode) -> Vec<[u8; 32]> {
+    let mut nodes = Vec::with_capacity(leaves.len());
+    for leaf in leaves {
+        nodes.push(mode.hash_leaf(leaf).expect("32-byte leaves hash in every mode"));
+    }
+    nodes
+}
+
+/// Reduce a chunk of leaf nodes exactly `levels` levels.
+///
+/// # Description
+/// A chunk that runs out of pairs early is the tail of the tree, so its
+/// single node is still the unpaired last node of the global level and 
+/// goes through `parent_level` like in the sequential build.
+fn reduce_chunk(
+    mut nodes: Vec<[u8; 32]>, 
+    mode: MerkleMode, 
+    levels: usize,
+) -> [u8; 32] {
+    let mut n = 0;
+    while n < levels {
+        nodes = parent_level(&nodes, mode);
+        n += 1;
+    }
+    nodes[0]
+}
+
+/// Reduce `nodes` until a single root remains; `leaf_level` tells whether a
+/// lone node is a leaf that `mode` still pairs with itself.
+fn reduce_to_root(
+    mut nodes: Vec<[u8; 32]>, 
+    mode: MerkleMode, 
+    leaf_level: bool,
+) -> [u8; 32] {
+    if leaf_level && nodes.len() == 1 && mode.pairs_single_leaf() {
+        nodes = parent_level(&nodes, mode);
+    }
+    while nodes.len() > 1 {
+        nodes = parent_level(&nodes, mode);
+    }
+    nodes[0]
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::{next, random_leaves};
+    use crate::merkle_tree::{merkle_tree, MerkleTree};
+
+    #[test]
+    fn empty_leaves_have_no_root() {
+        assert_eq!((parallel_merkle_root(&[], MerkleMode::DuplicateLast, 4)), (None));
+    }
+
+    #[test]
+    fn matches_merkle_tree_on_random_leaf_counts() {
+        let mut state = 0x9e37_79b9_7f4a_7c15u64;
+        for _ in 0..40 {
+            let n = (next(&mut state) % 3000) as usize + 1;
+            let threads = (next(&mut state) % 9) as usize + 1;
+            let data = random_leaves(n, &mut state);
+
+            let result = parallel_merkle_root(&data, MerkleMode::DuplicateLast, threads);
+
+            assert_eq!((result), (Some(merkle_tree(data))));
+        }
+    }
+
+    #[test]
+    fn matches_stored_tree_for_small_odd_and_even_counts_in_every_mode() {
+        let mut state = 0x2545_f491_4f6c_dd1du64;
+        let modes = [MerkleMode::DuplicateLast, MerkleMode::Rfc6962, MerkleMode::Bitcoin];
+        for mode in modes {
+            for n in 1..=40 {
+                let data = random_leaves(n, &mut state);
+                let expected = MerkleTree::with_mode(&data, mode).unwrap().root();
+                for threads in 1..=6 {
+                    let result = parallel_merkle_root(&data, mode, threads);
+
+                    assert_eq!((result), (Some(expected)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn default_thread_count_matches_merkle_tree() {
+        let mut state = 0x0123_4567_89ab_cdefu64;
+        let data = random_leaves(20_001, &mut state);
+
+        assert_eq!((merkle_tree_parallel(data.clone())), (merkle_tree(data)));
+    }
+}
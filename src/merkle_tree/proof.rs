@@ -0,0 +1,302 @@
+use crate::merkle_tree::{MerkleMode, MerkleTree};
+
+/// Inclusion proof for one leaf of a `MerkleTree`.
+///
+/// # Description
+/// - `siblings[k]` is the sibling of the path node at level k, from the leaf 
+///   level up to just below the root.
+/// - `directions[k]` is `true` when the path node is a right child, so the 
+///   sibling is hashed on the left.
+/// - When the path node is the unpaired last node of an odd level, its 
+///   sibling is the node itself in `DuplicateLast` and `Bitcoin` modes (the
+///   duplicated last node). In `Rfc6962` mode the node is promoted and the 
+///   level has no entry.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MerkleProof {
+    pub leaf_count: usize,
+    pub siblings: Vec<[u8; 32]>,
+    pub directions: Vec<bool>,
+}
+
+impl MerkleTree {
+    /// Inclusion proof for the leaf at `index`, or `None` if out of range.
+    pub fn proof(&self, index: usize) -> Option<MerkleProof> {
+        if index >= self.len() {
+            return None;
+        }
+
+        let mut siblings: Vec<[u8; 32]> = Vec::new();
+        let mut directions: Vec<bool> = Vec::new();
+        let mut i = index;
+        let mut n = 0;
+        // Every level below the root contributes one sibling.
+        while n + 1 < self.depth() {
+            let nodes = self.level(n).unwrap();
+            let sibling = i ^ 1;
+            if sibling < nodes.len() {
+                siblings.push(nodes[sibling]);
+                directions.push(i % 2 == 1);
+            } else if !self.mode().promotes_unpaired() {
+                // Odd level: the last node is paired with itself.
+                siblings.push(nodes[i]);
+                directions.push(false);
+            }
+            i /= 2;
+            n += 1;
+        }
+
+        Some(MerkleProof { leaf_count: self.len(), siblings, directions })
+    }
+}
+
+/// Verify an inclusion proof for a `DuplicateLast` tree without the full 
+/// tree.
+///
+/// # Arguments
+/// - `root` - the Merkle root the leaf should belong to.
+/// - `leaf` - the raw leaf record (it is hashed like in `leaf_loading`).
+/// - `index` - position of the leaf in the tree.
+/// - `proof` - proof produced by `MerkleTree::proof`.
+///
+/// # Description
+/// - Check the proof has one sibling per level of a tree of `leaf_count` 
+///   leaves, and that the direction bits match the bits of `index`.
+/// - Where the path node is the unpaired last node of an odd level, require
+///   the sibling to be the node itself (odd-node duplication rule).
+/// - Hash up the path with `branching` and compare against `root`.
+///
+/// # Returns
+/// `true` if the leaf is included at `index` under `root`.
+pub fn verify_proof(
+    root: [u8; 32], 
+    leaf: &[u8], 
+    index: usize, 
+    proof: &MerkleProof,
+) -> bool {
+    verify_proof_with(MerkleMode::DuplicateLast, root, leaf, index, proof)
+}
+
+/// Verify an inclusion proof for a tree built in `mode`.
+///
+/// # Description
+/// Same checks as `verify_proof`; in `Rfc6962` mode the unpaired last node 
+/// of an odd level is promoted without consuming a sibling. `leaf` is any 
+/// record accepted by `MerkleTree::from_data` in that mode.
+///
+/// # Returns
+/// `true` if the leaf is included at `index` under `root`; `false` as well
+/// for a record the mode cannot hash (not 32 bytes in `Bitcoin` mode).
+pub fn verify_proof_with(
+    mode: MerkleMode,
+    root: [u8; 32], 
+    leaf: &[u8], 
+    index: usize, 
+    proof: &MerkleProof,
+) -> bool {
+    match mode.hash_leaf(leaf) {
+        Some(node) => verify_proof_from_node(mode, root, node, index, proof),
+        None => false,
+    }
+}
+
+/// Verify an inclusion proof starting from an already hashed leaf node.
+///
+/// # Arguments
+/// - `node` - the leaf node as stored in level 0, e.g. one of the nodes a 
+///   tree was built from with `MerkleTree::from_hashed`.
+///
+/// # Description
+/// Same checks as `verify_proof_with`, without hashing the leaf first.
+pub fn verify_proof_from_node(
+    mode: MerkleMode,
+    root: [u8; 32], 
+    node: [u8; 32], 
+    index: usize, 
+    proof: &MerkleProof,
+) -> bool {
+    if index >= proof.leaf_count 
+        || proof.siblings.len() != proof.directions.len() 
+    {
+        return false;
+    }
+
+    let mut node = node;
+    let mut i = index;
+    let mut width = proof.leaf_count;
+    let mut first = true;
+    let mut k = 0;
+    // Walk up until the root level; in `DuplicateLast` mode a single leaf 
+    // still pairs with itself.
+    while width > 1 || (first && mode.pairs_single_leaf()) {
+        let unpaired = i + 1 == width && width % 2 == 1;
+        if unpaired && mode.promotes_unpaired() {
+            // Promoted unchanged, no sibling on this level.
+            i /= 2;
+            width = width.div_ceil(2);
+            first = false;
+            continue;
+        }
+
+        if k >= proof.siblings.len() || proof.directions[k] != (i % 2 == 1) {
+            return false;
+        }
+
+        let sibling = proof.siblings[k];
+        if unpaired && sibling != node {
+            return false;
+        }
+
+        node = if proof.directions[k] {
+            mode.hash_node(sibling, node)
+        } else {
+            mode.hash_node(node, sibling)
+        };
+        i /= 2;
+        width = width.div_ceil(2);
+        first = false;
+        k += 1;
+    }
+
+    k == proof.siblings.len() && node == root
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::leaves;
+    use crate::merkle_tree::merkle_tree;
+    use crate::sha256::sha256;
+
+    #[test]
+    fn every_leaf_verifies_against_merkle_tree_root() {
+        for n in 1..=20 {
+            let data = leaves(n);
+            let tree = MerkleTree::new(&data).unwrap();
+            let root = merkle_tree(data.clone());
+
+            for (i, leaf) in data.iter().enumerate() {
+                let proof = tree.proof(i).unwrap();
+
+                assert!(verify_proof(root, leaf, i, &proof));
+            }
+        }
+    }
+
+    #[test]
+    fn proof_for_duplicated_last_node_uses_itself_as_sibling() {
+        let data = leaves(7);
+        let tree = MerkleTree::new(&data).unwrap();
+
+        let proof = tree.proof(6).unwrap();
+
+        assert_eq!((proof.siblings[0]), (sha256(&data[6])));
+        assert_eq!((proof.directions), (vec![false, true, true]));
+    }
+
+    #[test]
+    fn rejects_wrong_leaf_index_or_root() {
+        let data = leaves(6);
+        let tree = MerkleTree::new(&data).unwrap();
+        let proof = tree.proof(2).unwrap();
+
+        assert!(!verify_proof(tree.root(), &data[3], 2, &proof));
+        assert!(!verify_proof(tree.root(), &data[2], 3, &proof));
+        assert!(!verify_proof([0u8; 32], &data[2], 2, &proof));
+    }
+
+    #[test]
+    fn rejects_tampered_sibling_direction_or_length() {
+        let data = leaves(6);
+        let tree = MerkleTree::new(&data).unwrap();
+        let proof = tree.proof(4).unwrap();
+
+        let mut bad = proof.clone();
+        bad.siblings[1][0] ^= 0x01;
+        assert!(!verify_proof(tree.root(), &data[4], 4, &bad));
+
+        let mut bad = proof.clone();
+        bad.directions[0] = !bad.directions[0];
+        assert!(!verify_proof(tree.root(), &data[4], 4, &bad));
+
+        let mut bad = proof.clone();
+        bad.siblings.pop();
+        bad.directions.pop();
+        assert!(!verify_proof(tree.root(), &data[4], 4, &bad));
+    }
+
+    #[test]
+    fn out_of_range_index_has_no_proof() {
+        let tree = MerkleTree::new(&leaves(4)).unwrap();
+
+        assert_eq!((tree.proof(4)), (None));
+    }
+
+    #[test]
+    fn rfc6962_proofs_skip_promoted_levels() {
+        for n in 1..=20 {
+            let data = leaves(n);
+            let tree = MerkleTree::with_mode(&data, MerkleMode::Rfc6962).unwrap();
+
+            for (i, leaf) in data.iter().enumerate() {
+                let proof = tree.proof(i).unwrap();
+
+                assert!(verify_proof_with(
+                    MerkleMode::Rfc6962, tree.root(), leaf, i, &proof
+                ));
+                assert!(!verify_proof(tree.root(), leaf, i, &proof));
+            }
+        }
+
+        // Leaf 4 of 5 is promoted twice, then paired with the left subtree.
+        let tree = MerkleTree::with_mode(&leaves(5), MerkleMode::Rfc6962).unwrap();
+        assert_eq!((tree.proof(4).unwrap().siblings.len()), (1));
+    }
+
+    #[test]
+    fn proofs_verify_for_variable_length_records() {
+        let mut records: Vec<Vec<u8>> = Vec::new();
+        for i in 0..11usize {
+            records.push(vec![i as u8; i * 5]);
+        }
+        let modes = [MerkleMode::DuplicateLast, MerkleMode::Rfc6962];
+        for mode in modes {
+            let tree = MerkleTree::from_data_iter(&records, mode).unwrap();
+
+            for (i, record) in records.iter().enumerate() {
+                let proof = tree.proof(i).unwrap();
+
+                assert!(verify_proof_with(mode, tree.root(), record, i, &proof));
+                assert!(!verify_proof_with(mode, tree.root(), &[0xff; 3], i, &proof));
+            }
+        }
+    }
+
+    #[test]
+    fn proofs_verify_from_pre_hashed_leaf_nodes() {
+        let mut nodes: Vec<[u8; 32]> = Vec::new();
+        for leaf in &leaves(9) {
+            nodes.push(sha256(leaf));
+        }
+        let mode = MerkleMode::DuplicateLast;
+        let tree = MerkleTree::from_hashed(nodes.clone(), mode).unwrap();
+
+        for (i, node) in nodes.iter().enumerate() {
+            let proof = tree.proof(i).unwrap();
+
+            assert!(verify_proof_from_node(mode, tree.root(), *node, i, &proof));
+            // Passing the node as a raw leaf would hash it a second time.
+            assert!(!verify_proof_with(mode, tree.root(), node, i, &proof));
+        }
+    }
+
+    #[test]
+    fn bitcoin_proof_rejects_leaf_that_is_not_a_txid() {
+        let data = leaves(4);
+        let tree = MerkleTree::with_mode(&data, MerkleMode::Bitcoin).unwrap();
+        let proof = tree.proof(1).unwrap();
+
+        assert!(!verify_proof_with(
+            MerkleMode::Bitcoin, tree.root(), &data[1][..31], 1, &proof
+        ));
+    }
+}
@@ -0,0 +1,406 @@
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use crate::merkle_tree::branching;
+use crate::sha256::sha256;
+
+/// Height of the tree: one level per bit of a 256-bit key.
+pub const SPARSE_DEPTH: usize = 256;
+
+/// Sparse Merkle tree mapping 32-byte keys to values.
+///
+/// # Description
+/// - Every one of the 2^256 keys has a leaf; bit i of the key (most
+///   significant bit of `key[0]` first) picks the branch at depth i.
+/// - A present key's leaf is `sha256(value)`, an absent key's leaf is 32
+///   zero bytes. Parents are `branching(left, right)`.
+/// - `defaults[h]` is the root of an empty subtree of height h, so only
+///   nodes that differ from their default are stored and memory scales with
+///   the number of entries.
+#[derive(Clone, Debug)]
+pub struct SparseMerkleTree {
+    defaults: &'static [[u8; 32]],
+    values: HashMap<[u8; 32], Vec<u8>>,
+    // Non-default nodes, keyed by height (0 = leaf) and the path prefix of
+    // length 256 - height, with the remaining bits zeroed.
+    nodes: HashMap<(usize, [u8; 32]), [u8; 32]>,
+}
+
+/// Compressed inclusion or exclusion proof for one key.
+///
+/// # Description
+/// - Bit h of `bitmap` (bit h % 8 of byte h / 8) is set when the sibling at
+///   height h is not the default empty-subtree hash.
+/// - `siblings` lists only those non-default siblings, from the leaf up.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SparseProof {
+    pub bitmap: [u8; 32],
+    pub siblings: Vec<[u8; 32]>,
+}
+
+impl SparseMerkleTree {
+    /// Create an empty tree.
+    pub fn new() -> Self {
+        SparseMerkleTree {
+            defaults: default_hashes(),
+            values: HashMap::new(),
+            nodes: HashMap::new(),
+        }
+    }
+
+    /// Root hash; equals the default root for an empty tree.
+    pub fn root(&self) -> [u8; 32] {
+        self.node(SPARSE_DEPTH, &[0u8; 32])
+    }
+
+    /// Number of stored entries.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// `true` if no entry is stored.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Value stored under `key`, if any.
+    pub fn get(&self, key: &[u8; 32]) -> Option<&[u8]> {
+        self.values.get(key).map(Vec::as_slice)
+    }
+
+    /// Store `value` under `key` and update the path to the root.
+    ///
+    /// # Returns
+    /// The previous value under `key`, if any.
+    pub fn insert(&mut self, key: [u8; 32], value: &[u8]) -> Option<Vec<u8>> {
+        self.update_path(&key, sha256(value));
+        self.values.insert(key, value.to_vec())
+    }
+
+    /// Remove `key` and reset its path to the default hashes where possible.
+    ///
+    /// # Returns
+    /// The removed value, or `None` if `key` was absent.
+    pub fn remove(&mut self, key: &[u8; 32]) -> Option<Vec<u8>> {
+        let removed = self.values.remove(key);
+        if removed.is_some() {
+            self.update_path(key, self.defaults[0]);
+        }
+        removed
+    }
+
+    /// Proof for `key`: of membership if it is present, of non-membership
+    /// otherwise.
+    pub fn proof(&self, key: &[u8; 32]) -> SparseProof {
+        let mut bitmap = [0u8; 32];
+        let mut siblings: Vec<[u8; 32]> = Vec::new();
+        let mut h = 0;
+        while h < SPARSE_DEPTH {
+            let sibling = self.node(h, &sibling_prefix(key, h));
+            if sibling != self.defaults[h] {
+                bitmap[h / 8] |= 1 << (h % 8);
+                siblings.push(sibling);
+            }
+            h += 1;
+        }
+
+        SparseProof { bitmap, siblings }
+    }
+
+    /// Node at `height` on the path of `prefix`, or the default hash.
+    fn node(&self, height: usize, prefix: &[u8; 32]) -> [u8; 32] {
+        match self.nodes.get(&(height, *prefix)) {
+            Some(node) => *node,
+            None => self.defaults[height],
+        }
+    }
+
+    /// Set the leaf of `key` and recompute every ancestor, dropping nodes
+    /// that fall back to their default.
+    fn update_path(&mut self, key: &[u8; 32], leaf: [u8; 32]) {
+        let mut node = leaf;
+        let mut h = 0;
+        loop {
+            let slot = (h, prefix(key, SPARSE_DEPTH - h));
+            if node == self.defaults[h] {
+                self.nodes.remove(&slot);
+            } else {
+                self.nodes.insert(slot, node);
+            }
+            if h == SPARSE_DEPTH {
+                break;
+            }
+
+            let sibling = self.node(h, &sibling_prefix(key, h));
+            node = if bit(key, SPARSE_DEPTH - 1 - h) {
+                branching(sibling, node)
+            } else {
+                branching(node, sibling)
+            };
+            h += 1;
+        }
+    }
+}
+
+impl Default for SparseMerkleTree {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Verify a sparse Merkle proof for `key`.
+///
+/// # Arguments
+/// - `root` - root of the sparse tree.
+/// - `key` - the 32-byte key.
+/// - `value` - `Some(value)` to prove membership, `None` to prove the key is
+///   absent.
+/// - `proof` - proof produced by `SparseMerkleTree::proof`.
+///
+/// # Description
+/// Start from the leaf (`sha256(value)` or the empty leaf) and hash up,
+/// taking the next listed sibling where the bitmap bit is set and the
+/// default hash of that height otherwise.
+///
+/// # Returns
+/// `true` if the proof recomputes `root` and uses every listed sibling.
+pub fn verify_sparse_proof(
+    root: [u8; 32],
+    key: &[u8; 32],
+    value: Option<&[u8]>,
+    proof: &SparseProof,
+) -> bool {
+    let defaults = default_hashes();
+    let mut node = match value {
+        Some(value) => sha256(value),
+        None => defaults[0],
+    };
+
+    let mut k = 0;
+    let mut h = 0;
+    while h < SPARSE_DEPTH {
+        let sibling = if proof.bitmap[h / 8] & (1 << (h % 8)) != 0 {
+            if k >= proof.siblings.len() {
+                return false;
+            }
+            k += 1;
+            proof.siblings[k - 1]
+        } else {
+            defaults[h]
+        };
+
+        node = if bit(key, SPARSE_DEPTH - 1 - h) {
+            branching(sibling, node)
+        } else {
+            branching(node, sibling)
+        };
+        h += 1;
+    }
+
+    k == proof.siblings.len() && node == root
+}
+
+/// Empty-subtree hashes: `[0; 32]` for an empty leaf, then each height is
+/// the empty subtree below paired with itself.
+///
+/// The 256 hashes are computed on first use and shared by every tree and
+/// every proof verification.
+fn default_hashes() -> &'static [[u8; 32]] {
+    static DEFAULTS: OnceLock<Vec<[u8; 32]>> = OnceLock::new();
+    DEFAULTS.get_or_init(|| {
+        let mut defaults: Vec<[u8; 32]> = vec![[0u8; 32]];
+        let mut h = 0;
+        while h < SPARSE_DEPTH {
+            defaults.push(branching(defaults[h], defaults[h]));
+            h += 1;
+        }
+        defaults
+    })
+}
+
+/// Bit `i` of `key`, most significant bit of `key[0]` first.
+fn bit(key: &[u8; 32], i: usize) -> bool {
+    (key[i / 8] >> (7 - i % 8)) & 1 == 1
+}
+
+/// First `len` bits of `key`, with the remaining bits zeroed.
+fn prefix(key: &[u8; 32], len: usize) -> [u8; 32] {
+    let mut out = [0u8; 32];
+    let mut i = 0;
+    while i < len {
+        if bit(key, i) {
+            out[i / 8] |= 1 << (7 - i % 8);
+        }
+        i += 1;
+    }
+    out
+}
+
+/// Prefix of the sibling of the node at `height` on the path of `key`.
+fn sibling_prefix(key: &[u8; 32], height: usize) -> [u8; 32] {
+    let len = SPARSE_DEPTH - height;
+    let mut out = prefix(key, len);
+    // Flip the last bit of the prefix.
+    let i = len - 1;
+    out[i / 8] ^= 1 << (7 - i % 8);
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn key(n: u8) -> [u8; 32] {
+        let mut k = sha256(&[n]);
+        // Keep a shared prefix between some keys to exercise deep branches.
+        k[0] = n % 3;
+        k
+    }
+
+    #[test]
+    fn empty_tree_root_is_the_default_root() {
+        let tree = SparseMerkleTree::new();
+
+        assert!(tree.is_empty());
+        assert_eq!((tree.root()), (default_hashes()[SPARSE_DEPTH]));
+    }
+
+    #[test]
+    fn default_hashes_are_computed_once_and_shared() {
+        let tree = SparseMerkleTree::new();
+
+        assert!(std::ptr::eq(tree.defaults, default_hashes()));
+        assert_eq!((default_hashes().len()), (SPARSE_DEPTH + 1));
+    }
+
+    #[test]
+    fn single_entry_root_folds_defaults_up_the_path() {
+        let mut tree = SparseMerkleTree::new();
+        let k = key(7);
+        tree.insert(k, b"value");
+
+        let defaults = default_hashes();
+        let mut expected = sha256(b"value");
+        for (h, default) in defaults.iter().take(SPARSE_DEPTH).enumerate() {
+            expected = if bit(&k, SPARSE_DEPTH - 1 - h) {
+                branching(*default, expected)
+            } else {
+                branching(expected, *default)
+            };
+        }
+
+        assert_eq!((tree.root()), (expected));
+    }
+
+    #[test]
+    fn insert_get_and_remove() {
+        let mut tree = SparseMerkleTree::new();
+
+        assert_eq!((tree.insert(key(1), b"one")), (None));
+        assert_eq!((tree.insert(key(2), b"two")), (None));
+        assert_eq!((tree.insert(key(1), b"uno")), (Some(b"one".to_vec())));
+        assert_eq!((tree.get(&key(1))), (Some(&b"uno"[..])));
+        assert_eq!((tree.get(&key(3))), (None));
+        assert_eq!((tree.len()), (2));
+
+        assert_eq!((tree.remove(&key(2))), (Some(b"two".to_vec())));
+        assert_eq!((tree.remove(&key(2))), (None));
+        assert_eq!((tree.get(&key(2))), (None));
+    }
+
+    #[test]
+    fn root_does_not_depend_on_insertion_order() {
+        let mut forward = SparseMerkleTree::new();
+        let mut backward = SparseMerkleTree::new();
+        for n in 0..20u8 {
+            forward.insert(key(n), &[n; 3]);
+        }
+        for n in (0..20u8).rev() {
+            backward.insert(key(n), &[n; 3]);
+        }
+
+        assert_eq!((forward.root()), (backward.root()));
+    }
+
+    #[test]
+    fn removing_every_entry_frees_every_node() {
+        let mut tree = SparseMerkleTree::new();
+        let empty_root = tree.root();
+        for n in 0..10u8 {
+            tree.insert(key(n), &[n]);
+        }
+        // Roughly one path of nodes per entry, not 2^256.
+        assert!(tree.nodes.len() <= 10 * (SPARSE_DEPTH + 1));
+
+        for n in 0..10u8 {
+            tree.remove(&key(n));
+        }
+
+        assert_eq!((tree.root()), (empty_root));
+        assert_eq!((tree.nodes.len()), (0));
+    }
+
+    #[test]
+    fn membership_proofs_verify() {
+        let mut tree = SparseMerkleTree::new();
+        for n in 0..12u8 {
+            tree.insert(key(n), &[n, n]);
+        }
+
+        for n in 0..12u8 {
+            let proof = tree.proof(&key(n));
+
+            assert!(verify_sparse_proof(tree.root(), &key(n), Some(&[n, n]), &proof));
+            assert!(!verify_sparse_proof(tree.root(), &key(n), Some(&[n]), &proof));
+            assert!(!verify_sparse_proof(tree.root(), &key(n), None, &proof));
+        }
+    }
+
+    #[test]
+    fn non_membership_proofs_verify() {
+        let mut tree = SparseMerkleTree::new();
+        for n in 0..12u8 {
+            tree.insert(key(n), &[n]);
+        }
+
+        for n in 12..30u8 {
+            let proof = tree.proof(&key(n));
+
+            assert!(verify_sparse_proof(tree.root(), &key(n), None, &proof));
+            assert!(!verify_sparse_proof(tree.root(), &key(n), Some(&[n]), &proof));
+        }
+    }
+
+    #[test]
+    fn proofs_only_carry_non_default_siblings() {
+        let mut tree = SparseMerkleTree::new();
+        tree.insert(key(4), b"only");
+
+        let proof = tree.proof(&key(4));
+
+        assert_eq!((proof.siblings.len()), (0));
+        assert_eq!((proof.bitmap), ([0u8; 32]));
+        assert!(verify_sparse_proof(tree.root(), &key(4), Some(b"only"), &proof));
+    }
+
+    #[test]
+    fn rejects_tampered_proof() {
+        let mut tree = SparseMerkleTree::new();
+        for n in 0..8u8 {
+            tree.insert(key(n), &[n]);
+        }
+        let proof = tree.proof(&key(3));
+
+        let mut bad = proof.clone();
+        bad.siblings[0][0] ^= 0x01;
+        assert!(!verify_sparse_proof(tree.root(), &key(3), Some(&[3]), &bad));
+
+        let mut bad = proof.clone();
+        bad.siblings.push([0u8; 32]);
+        assert!(!verify_sparse_proof(tree.root(), &key(3), Some(&[3]), &bad));
+
+        let mut bad = proof.clone();
+        bad.bitmap = [0u8; 32];
+        assert!(!verify_sparse_proof(tree.root(), &key(3), Some(&[3]), &bad));
+    }
+}
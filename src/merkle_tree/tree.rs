@@ -0,0 +1,490 @@
+use crate::merkle_tree::MerkleMode;
+
+/// Merkle tree that keeps every level in memory.
+///
+/// # Description
+/// - Level 0 holds the hashed leaves, the last level holds only the root.
+/// - Levels are stored without padding. When a level has an odd number of 
+///   nodes, its last node is handled by the tree's `MerkleMode`: paired with
+///   itself (`DuplicateLast`, exactly like `leaf_loading` and `merkle_tree`)
+///   or promoted (`Rfc6962`).
+/// - In `DuplicateLast` mode a single leaf is still paired with itself, so 
+///   `root()` always equals `merkle_tree` over the same leaves.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MerkleTree {
+    mode: MerkleMode,
+    levels: Vec<Vec<[u8; 32]>>,
+}
+
+impl MerkleTree {
+    /// Build the tree from raw 32-byte leaves (transactions), in the default
+    /// `DuplicateLast` mode.
+    ///
+    /// # Returns
+    /// `None` if `leaves` is empty, since an empty tree has no root.
+    pub fn new(leaves: &[[u8; 32]]) -> Option<Self> {
+        Self::with_mode(leaves, MerkleMode::DuplicateLast)
+    }
+
+    /// Build the tree from raw 32-byte leaves in the given `mode`.
+    ///
+    /// # Returns
+    /// `None` if `leaves` is empty.
+    pub fn with_mode(leaves: &[[u8; 32]], mode: MerkleMode) -> Option<Self> {
+        Self::from_data_iter(leaves, mode)
+    }
+
+    /// Build the tree from variable-length leaf records in the given `mode`.
+    ///
+    /// # Description
+    /// Each record is hashed directly with `mode.hash_leaf`, so it doesn't 
+    /// have to be 32 bytes (except in `Bitcoin` mode, where records are 
+    /// txids). Records of exactly 32 bytes give the same root as `with_mode`.
+    ///
+    /// # Returns
+    /// `None` if `leaves` is empty, or in `Bitcoin` mode if a record is not
+    /// 32 bytes long.
+    pub fn from_data(leaves: &[&[u8]], mode: MerkleMode) -> Option<Self> {
+        Self::from_data_iter(leaves, mode)
+    }
+
+    /// Build the tree from any iterator of leaf records in the given `mode`.
+    ///
+    /// # Description
+    /// Same as `from_data`, for records held as `Vec<u8>`, `String`, chunks 
+    /// of a file, etc.
+    ///
+    /// # Returns
+    /// `None` if the iterator yields no leaves, or in `Bitcoin` mode if a 
+    /// record is not 32 bytes long.
+    pub fn from_data_iter<I>(leaves: I, mode: MerkleMode) -> Option<Self>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<[u8]>,
+    {
+        let mut leaf_nodes: Vec<[u8; 32]> = Vec::new();
+        for leaf in leaves {
+            // Hash and store data.
+            leaf_nodes.push(mode.hash_leaf(leaf.as_ref())?);
+        }
+
+        Self::from_hashed(leaf_nodes, mode)
+    }
+
+    /// Build the tree from leaf nodes that are already hashed.
+    ///
+    /// # Description
+    /// `leaf_nodes` becomes level 0 as-is, without hashing again. They must 
+    /// be hashed the way `mode.hash_leaf` would for proofs to verify.
+    ///
+    /// # Returns
+    /// `None` if `leaf_nodes` is empty.
+    pub fn from_hashed(leaf_nodes: Vec<[u8; 32]>, mode: MerkleMode) -> Option<Self> {
+        if leaf_nodes.is_empty() {
+            return None;
+        }
+
+        let mut levels = vec![leaf_nodes];
+        while levels[levels.len() - 1].len() > 1 
+            || (levels.len() == 1 && mode.pairs_single_leaf()) 
+        {
+            let parents = parent_level(&levels[levels.len() - 1], mode);
+            levels.push(parents);
+        }
+
+        Some(MerkleTree { mode, levels })
+    }
+
+    /// Mode the tree was built in.
+    pub fn mode(&self) -> MerkleMode {
+        self.mode
+    }
+
+    /// The Merkle root.
+    pub fn root(&self) -> [u8; 32] {
+        self.levels[self.levels.len() - 1][0]
+    }
+
+    /// Number of leaves the tree was built from.
+    pub fn len(&self) -> usize {
+        self.levels[0].len()
+    }
+
+    /// Always `false`: a tree is built from at least one leaf.
+    pub fn is_empty(&self) -> bool {
+        self.levels[0].is_empty()
+    }
+
+    /// Hashed leaf node at index `i`, or `None` if out of range.
+    pub fn leaf(&self, i: usize) -> Option<[u8; 32]> {
+        if i < self.levels[0].len() {
+            Some(self.levels[0][i])
+        } else {
+            None
+        }
+    }
+
+    /// Nodes of level `n` (0 = leaf nodes), or `None` if above the root.
+    pub fn level(&self, n: usize) -> Option<&[[u8; 32]]> {
+        if n < self.levels.len() {
+            Some(&self.levels[n])
+        } else {
+            None
+        }
+    }
+
+    /// Number of levels, including the leaf level and the root level.
+    pub fn depth(&self) -> usize {
+        self.levels.len()
+    }
+
+    /// Replace the leaf at `index` and recompute only its ancestors.
+    ///
+    /// # Arguments
+    /// - `index` - position of the leaf to replace.
+    /// - `leaf` - the new raw leaf, hashed with the tree's `MerkleMode`.
+    ///
+    /// # Returns
+    /// The new root, or `None` (tree unchanged) if `index` is out of range
+    /// or `leaf` is not a valid leaf for the mode (see `from_data`).
+    pub fn update(&mut self, index: usize, leaf: &[u8]) -> Option<[u8; 32]> {
+        self.update_batch(&[(index, leaf)])
+    }
+
+    /// Replace several leaves, recomputing each shared ancestor once.
+    ///
+    /// # Arguments
+    /// - `updates` - `(index, new leaf)` pairs; a later pair for the same 
+    ///   index wins.
+    ///
+    /// # Description
+    /// - Write the new leaf nodes, then walk up level by level keeping the 
+    ///   sorted, deduplicated positions whose parent must change.
+    /// - Each parent is rehashed from the two children stored below it (or 
+    ///   from the unpaired last child, per `MerkleMode`), so touching k 
+    ///   leaves costs at most k * log2(n) hashes.
+    ///
+    /// # Returns
+    /// The new root, or `None` (tree unchanged) if any index is out of range
+    /// or any leaf is not valid for the mode. Every pair is checked before
+    /// the first write.
+    pub fn update_batch(&mut self, updates: &[(usize, &[u8])]) -> Option<[u8; 32]> {
+        // Hash every new leaf before the first write.
+        let mut new_nodes: Vec<(usize, [u8; 32])> = Vec::with_capacity(updates.len());
+        for (index, leaf) in updates {
+            if *index >= self.len() {
+                return None;
+            }
+            new_nodes.push((*index, self.mode.hash_leaf(leaf)?));
+        }
+
+        let mut dirty: Vec<usize> = Vec::with_capacity(updates.len());
+        for (index, node) in new_nodes {
+            self.levels[0][index] = node;
+            dirty.push(index);
+        }
+
+        let mut n = 0;
+        while n + 1 < self.levels.len() {
+            // Parents of the dirty nodes, in order and without repeats.
+            let mut parents: Vec<usize> = Vec::with_capacity(dirty.len());
+            for i in &dirty {
+                parents.push(i / 2);
+            }
+            parents.sort_unstable();
+            parents.dedup();
+
+            for p in &parents {
+                let nodes = &self.levels[n];
+                let left = 2 * p;
+                let parent = if left + 1 < nodes.len() {
+                    self.mode.hash_node(nodes[left], nodes[left + 1])
+                } else {
+                    self.mode.hash_unpaired(nodes[left])
+                };
+                self.levels[n + 1][*p] = parent;
+            }
+
+            dirty = parents;
+            n += 1;
+        }
+
+        Some(self.root())
+    }
+}
+
+/// Build the parent level; an odd last node is duplicated or promoted 
+/// depending on `mode`.
+pub(crate) fn parent_level(nodes: &[[u8; 32]], mode: MerkleMode) -> Vec<[u8; 32]> {
+    let mut parents: Vec<[u8; 32]> = Vec::with_capacity(nodes.len().div_ceil(2));
+    let mut i = 0;
+    while i < nodes.len() {
+        if i + 1 < nodes.len() {
+            parents.push(mode.hash_node(nodes[i], nodes[i + 1]));
+        } else {
+            // Unpaired last node if odd.
+            parents.push(mode.hash_unpaired(nodes[i]));
+        }
+        // Get index per 2 (0-1, 2-3, 4-5, etc).
+        i += 2;
+    }
+
+    parents
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::leaves;
+    use crate::merkle_tree::{branching, merkle_tree};
+    use crate::sha256::sha256;
+
+    #[test]
+    fn root_matches_merkle_tree_for_even_and_odd_counts() {
+        for n in 1..=33 {
+            let data = leaves(n);
+            let tree = MerkleTree::new(&data).unwrap();
+
+            assert_eq!((tree.root()), (merkle_tree(data)));
+        }
+    }
+
+    #[test]
+    fn empty_leaves_build_no_tree() {
+        assert_eq!((MerkleTree::new(&[])), (None));
+    }
+
+    #[test]
+    fn stores_every_level_up_to_the_root() {
+        let data = leaves(5);
+        let tree = MerkleTree::new(&data).unwrap();
+
+        let h: Vec<[u8; 32]> = data.iter().map(|l| sha256(l)).collect();
+        let h01 = branching(h[0], h[1]);
+        let h23 = branching(h[2], h[3]);
+        let h44 = branching(h[4], h[4]);
+        let h0123 = branching(h01, h23);
+        let h4444 = branching(h44, h44);
+
+        assert_eq!((tree.len()), (5));
+        assert_eq!((tree.depth()), (4));
+        assert_eq!((tree.level(0).unwrap()), (&h[..]));
+        assert_eq!((tree.level(1).unwrap()), (&[h01, h23, h44][..]));
+        assert_eq!((tree.level(2).unwrap()), (&[h0123, h4444][..]));
+        assert_eq!((tree.level(3).unwrap()), (&[tree.root()][..]));
+        assert_eq!((tree.level(4)), (None));
+    }
+
+    #[test]
+    fn leaf_returns_hashed_leaf_or_none() {
+        let data = leaves(3);
+        let tree = MerkleTree::new(&data).unwrap();
+
+        assert_eq!((tree.leaf(2)), (Some(sha256(&data[2]))));
+        assert_eq!((tree.leaf(3)), (None));
+    }
+
+    #[test]
+    fn single_leaf_is_paired_with_itself() {
+        let tree = MerkleTree::new(&[[0xab; 32]]).unwrap();
+        let h = sha256(&[0xab; 32]);
+
+        assert_eq!((tree.root()), (branching(h, h)));
+        assert_eq!((tree.depth()), (2));
+    }
+
+    #[test]
+    fn from_data_with_32_byte_records_matches_new() {
+        for n in 1..=9 {
+            let data = leaves(n);
+            let mut records: Vec<&[u8]> = Vec::new();
+            for leaf in &data {
+                records.push(leaf);
+            }
+
+            let result = MerkleTree::from_data(&records, MerkleMode::DuplicateLast);
+            let expected = MerkleTree::new(&data);
+
+            assert_eq!((result), (expected));
+        }
+    }
+
+    #[test]
+    fn from_data_hashes_variable_length_records() {
+        let records: [&[u8]; 3] = [
+            b"{\"id\":1}", 
+            b"", 
+            b"a longer record of chunked file data",
+        ];
+
+        let mode = MerkleMode::DuplicateLast;
+        let tree = MerkleTree::from_data(&records, mode).unwrap();
+        let h01 = branching(sha256(records[0]), sha256(records[1]));
+        let h22 = branching(sha256(records[2]), sha256(records[2]));
+
+        assert_eq!((tree.leaf(1)), (Some(sha256(b""))));
+        assert_eq!((tree.root()), (branching(h01, h22)));
+    }
+
+    #[test]
+    fn from_data_iter_accepts_owned_records() {
+        let mut records: Vec<Vec<u8>> = Vec::new();
+        for i in 0..7usize {
+            records.push(vec![i as u8; i * 13]);
+        }
+        let mut slices: Vec<&[u8]> = Vec::new();
+        for record in &records {
+            slices.push(record);
+        }
+
+        let mode = MerkleMode::Rfc6962;
+        let result = MerkleTree::from_data_iter(records.clone(), mode);
+        let expected = MerkleTree::from_data(&slices, mode);
+        let empty: Vec<Vec<u8>> = Vec::new();
+
+        assert_eq!((result), (expected));
+        assert_eq!((MerkleTree::from_data_iter(empty, mode)), (None));
+    }
+
+    #[test]
+    fn from_data_rejects_bitcoin_records_that_are_not_txids() {
+        let txid = [0x6au8; 32];
+        let short = [0x6au8; 31];
+        let records: Vec<&[u8]> = vec![&txid, &short, &txid];
+        let owned: Vec<Vec<u8>> = vec![txid.to_vec(), short.to_vec()];
+
+        let mode = MerkleMode::Bitcoin;
+
+        assert_eq!((MerkleTree::from_data(&records, mode)), (None));
+        assert_eq!((MerkleTree::from_data_iter(owned, mode)), (None));
+        assert!(MerkleTree::from_data(&records, MerkleMode::Rfc6962).is_some());
+    }
+
+    #[test]
+    fn from_hashed_does_not_hash_again() {
+        let data = leaves(6);
+        let mut hashed: Vec<[u8; 32]> = Vec::new();
+        for leaf in &data {
+            hashed.push(sha256(leaf));
+        }
+
+        let mode = MerkleMode::DuplicateLast;
+        let result = MerkleTree::from_hashed(hashed, mode).unwrap();
+
+        assert_eq!((result.root()), (merkle_tree(data)));
+        assert_eq!((MerkleTree::from_hashed(Vec::new(), mode)), (None));
+    }
+
+    #[test]
+    fn rfc6962_promotes_unpaired_nodes() {
+        let data = leaves(5);
+        let tree = MerkleTree::with_mode(&data, MerkleMode::Rfc6962).unwrap();
+        let mode = MerkleMode::Rfc6962;
+
+        let h: Vec<[u8; 32]> = data.iter().map(|l| mode.hash_leaf(l).unwrap()).collect();
+        let h01 = mode.hash_node(h[0], h[1]);
+        let h23 = mode.hash_node(h[2], h[3]);
+        let h0123 = mode.hash_node(h01, h23);
+
+        assert_eq!((tree.level(1).unwrap()), (&[h01, h23, h[4]][..]));
+        assert_eq!((tree.level(2).unwrap()), (&[h0123, h[4]][..]));
+        assert_eq!((tree.root()), (mode.hash_node(h0123, h[4])));
+    }
+
+    #[test]
+    fn rfc6962_single_leaf_root_is_the_leaf_hash() {
+        let tree = MerkleTree::with_mode(&[[0xab; 32]], MerkleMode::Rfc6962).unwrap();
+
+        assert_eq!((tree.root()), (MerkleMode::Rfc6962.hash_leaf(&[0xab; 32]).unwrap()));
+        assert_eq!((tree.depth()), (1));
+    }
+
+    #[test]
+    fn rfc6962_odd_leaf_list_does_not_share_root_with_duplicated_list() {
+        let data = leaves(3);
+        let mut mutated = data.clone();
+        mutated.push(data[2]);
+
+        // CVE-2012-2459: the default mode cannot tell both lists apart.
+        let dup = MerkleTree::new(&data).unwrap();
+        let dup_mutated = MerkleTree::new(&mutated).unwrap();
+        assert_eq!((dup.root()), (dup_mutated.root()));
+
+        let ct = MerkleTree::with_mode(&data, MerkleMode::Rfc6962).unwrap();
+        let ct_mutated = MerkleTree::with_mode(&mutated, MerkleMode::Rfc6962).unwrap();
+        assert_ne!((ct.root()), (ct_mutated.root()));
+    }
+
+    #[test]
+    fn rfc6962_inner_node_cannot_pass_as_leaf() {
+        let data = leaves(4);
+        let ct = MerkleTree::with_mode(&data, MerkleMode::Rfc6962).unwrap();
+        let level_1 = ct.level(1).unwrap();
+
+        // Presenting the two inner nodes as leaves gives another root.
+        let forged = MerkleTree::with_mode(level_1, MerkleMode::Rfc6962).unwrap();
+
+        assert_ne!((forged.root()), (ct.root()));
+    }
+
+    #[test]
+    fn update_matches_rebuilt_tree_in_every_mode() {
+        let modes = [MerkleMode::DuplicateLast, MerkleMode::Rfc6962, MerkleMode::Bitcoin];
+        for mode in modes {
+            for n in 1..=13 {
+                let mut data = leaves(n);
+                let mut tree = MerkleTree::with_mode(&data, mode).unwrap();
+                for i in 0..n {
+                    data[i] = [0xe0 ^ i as u8; 32];
+                    let root = tree.update(i, &data[i]);
+                    let expected = MerkleTree::with_mode(&data, mode).unwrap();
+
+                    assert_eq!((root), (Some(expected.root())));
+                    assert_eq!((tree), (expected));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn batch_update_matches_sequential_updates() {
+        let data = leaves(21);
+        let new_leaves = leaves(40);
+        let mut batch = MerkleTree::new(&data).unwrap();
+        let mut one_by_one = batch.clone();
+
+        let mut updates: Vec<(usize, &[u8])> = Vec::new();
+        for i in [20, 3, 4, 5, 11, 3] {
+            updates.push((i, &new_leaves[i + 19]));
+        }
+        let root = batch.update_batch(&updates);
+        for (i, leaf) in &updates {
+            one_by_one.update(*i, leaf);
+        }
+
+        assert_eq!((root), (Some(one_by_one.root())));
+        assert_eq!((batch), (one_by_one));
+    }
+
+    #[test]
+    fn out_of_range_update_leaves_tree_unchanged() {
+        let data = leaves(5);
+        let mut tree = MerkleTree::new(&data).unwrap();
+        let before = tree.clone();
+
+        assert_eq!((tree.update(5, &[0u8; 32])), (None));
+        assert_eq!((tree.update_batch(&[(0, &[1u8; 32]), (9, &[2u8; 32])])), (None));
+        assert_eq!((tree), (before));
+    }
+
+    #[test]
+    fn bitcoin_update_with_wrong_leaf_length_leaves_tree_unchanged() {
+        let data = leaves(5);
+        let mut tree = MerkleTree::with_mode(&data, MerkleMode::Bitcoin).unwrap();
+        let before = tree.clone();
+
+        assert_eq!((tree.update(1, &[0u8; 31])), (None));
+        assert_eq!((tree.update_batch(&[(0, &[1u8; 32]), (2, &[2u8; 33])])), (None));
+        assert_eq!((tree), (before));
+    }
+}
@@ -0,0 +1,6 @@
+#[allow(clippy::module_inception)]
+pub mod pbkdf2;
+pub mod verify;
+
+pub use pbkdf2::pbkdf2_hmac_sha256;
+pub use verify::verify;
\ No newline at end of file
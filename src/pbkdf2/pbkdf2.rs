@@ -0,0 +1,225 @@
+use crate::hmac::HmacSha256;
+
+/// PBKDF2 with HMAC-SHA-256 as the pseudorandom function.
+///
+/// # Arguments
+/// - `password`: Password as a byte slice (`&[u8]`), of any length.
+/// - `salt`: Salt as a byte slice (`&[u8]`).
+/// - `iterations`: Iteration count `c` (must be at least 1).
+/// - `out`: Buffer receiving the derived key; its length is `dkLen`.
+///
+/// # Description
+/// - Key HMAC once with the password, and clone the keyed state for every
+///   PRF call, so the ipad/opad compressions are not repeated.
+/// - For each block i: U1 = PRF(P, S || INT(i)), Uj = PRF(P, Uj-1).
+/// - T_i = U1 ^ U2 ^ ... ^ Uc, and the derived key is T_1 || T_2 || ...
+///   truncated to `out.len()` bytes.
+///
+/// # Panics
+/// - If `iterations` is 0.
+/// - If `out` is longer than (2^32 - 1) * 32 bytes ("derived key too long"),
+///   since the block index is a 32-bit counter.
+///
+/// # Reference
+/// [RFC 8018, section 5.2](https://www.rfc-editor.org/rfc/rfc8018#section-5.2)
+pub fn pbkdf2_hmac_sha256(
+    password: &[u8], 
+    salt: &[u8], 
+    iterations: u32, 
+    out: &mut [u8],
+) {
+    assert!(iterations > 0, "PBKDF2 needs at least one iteration");
+    assert!(block_count(out.len()).is_some(), "derived key too long");
+
+    let keyed = HmacSha256::new(password);
+    let mut block_index: u32 = 1;
+    let mut offset = 0;
+
+    while offset < out.len() {
+        // U1 = PRF(P, S || INT(i)).
+        let mut mac = keyed.clone();
+        mac.update(salt);
+        mac.update(&[
+            (block_index >> 24) as u8,
+            (block_index >> 16) as u8,
+            (block_index >> 8) as u8,
+            block_index as u8,
+        ]);
+        let mut u = mac.finalize();
+        let mut t = u;
+
+        // Uj = PRF(P, Uj-1), XORed into T.
+        let mut j = 1;
+        while j < iterations {
+            let mut mac = keyed.clone();
+            mac.update(&u);
+            u = mac.finalize();
+
+            let mut k = 0;
+            while k < 32 {
+                t[k] ^= u[k];
+                k += 1;
+            }
+            j += 1;
+        }
+
+        // Copy T_i into the output, truncating the last block.
+        let mut k = 0;
+        while k < 32 && offset < out.len() {
+            out[offset] = t[k];
+            offset += 1;
+            k += 1;
+        }
+        // Wraps only after block 2^32 - 1, the last one allowed, which ends
+        // the loop.
+        block_index = block_index.wrapping_add(1);
+    }
+}
+
+/// Number of 32-byte blocks T_i needed for a `dk_len`-byte derived key.
+///
+/// # Returns
+/// The count, or `None` if it exceeds 2^32 - 1.
+pub(crate) fn block_count(dk_len: usize) -> Option<u32> {
+    u32::try_from(dk_len.div_ceil(32)).ok()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn one_iteration_32_bytes() {
+        let mut out = [0u8; 32];
+        pbkdf2_hmac_sha256(b"password", b"salt", 1, &mut out);
+
+        let expected = [
+            0x12, 0x0f, 0xb6, 0xcf, 0xfc, 0xf8, 0xb3, 0x2c,
+            0x43, 0xe7, 0x22, 0x52, 0x56, 0xc4, 0xf8, 0x37,
+            0xa8, 0x65, 0x48, 0xc9, 0x2c, 0xcc, 0x35, 0x48,
+            0x08, 0x05, 0x98, 0x7c, 0xb7, 0x0b, 0xe1, 0x7b,
+        ];
+
+        assert_eq!((out), (expected));
+    }
+
+    #[test]
+    fn two_iterations_32_bytes() {
+        let mut out = [0u8; 32];
+        pbkdf2_hmac_sha256(b"password", b"salt", 2, &mut out);
+
+        let expected = [
+            0xae, 0x4d, 0x0c, 0x95, 0xaf, 0x6b, 0x46, 0xd3,
+            0x2d, 0x0a, 0xdf, 0xf9, 0x28, 0xf0, 0x6d, 0xd0,
+            0x2a, 0x30, 0x3f, 0x8e, 0xf3, 0xc2, 0x51, 0xdf,
+            0xd6, 0xe2, 0xd8, 0x5a, 0x95, 0x47, 0x4c, 0x43,
+        ];
+
+        assert_eq!((out), (expected));
+    }
+
+    #[test]
+    fn four_thousand_ninety_six_iterations_32_bytes() {
+        let mut out = [0u8; 32];
+        pbkdf2_hmac_sha256(b"password", b"salt", 4096, &mut out);
+
+        let expected = [
+            0xc5, 0xe4, 0x78, 0xd5, 0x92, 0x88, 0xc8, 0x41,
+            0xaa, 0x53, 0x0d, 0xb6, 0x84, 0x5c, 0x4c, 0x8d,
+            0x96, 0x28, 0x93, 0xa0, 0x01, 0xce, 0x4e, 0x11,
+            0xa4, 0x96, 0x38, 0x73, 0xaa, 0x98, 0x13, 0x4a,
+        ];
+
+        assert_eq!((out), (expected));
+    }
+
+    #[test]
+    fn long_password_and_salt_40_bytes_spans_two_blocks() {
+        let mut out = [0u8; 40];
+        pbkdf2_hmac_sha256(
+            b"passwordPASSWORDpassword",
+            b"saltSALTsaltSALTsaltSALTsaltSALTsalt",
+            4096,
+            &mut out,
+        );
+
+        let expected = [
+            0x34, 0x8c, 0x89, 0xdb, 0xcb, 0xd3, 0x2b, 0x2f,
+            0x32, 0xd8, 0x14, 0xb8, 0x11, 0x6e, 0x84, 0xcf,
+            0x2b, 0x17, 0x34, 0x7e, 0xbc, 0x18, 0x00, 0x18,
+            0x1c, 0x4e, 0x2a, 0x1f, 0xb8, 0xdd, 0x53, 0xe1,
+            0xc6, 0x35, 0x51, 0x8c, 0x7d, 0xac, 0x47, 0xe9,
+        ];
+
+        assert_eq!((out), (expected));
+    }
+
+    #[test]
+    fn embedded_nul_bytes_16_bytes() {
+        let mut out = [0u8; 16];
+        pbkdf2_hmac_sha256(b"pass\0word", b"sa\0lt", 4096, &mut out);
+
+        let expected = [
+            0x89, 0xb6, 0x9d, 0x05, 0x16, 0xf8, 0x29, 0x89,
+            0x3c, 0x69, 0x62, 0x26, 0x65, 0x0a, 0x86, 0x87,
+        ];
+
+        assert_eq!((out), (expected));
+    }
+
+    #[test]
+    fn rfc7914_passwd_salt_one_iteration_64_bytes() {
+        let mut out = [0u8; 64];
+        pbkdf2_hmac_sha256(b"passwd", b"salt", 1, &mut out);
+
+        let expected = [
+            0x55, 0xac, 0x04, 0x6e, 0x56, 0xe3, 0x08, 0x9f,
+            0xec, 0x16, 0x91, 0xc2, 0x25, 0x44, 0xb6, 0x05,
+            0xf9, 0x41, 0x85, 0x21, 0x6d, 0xde, 0x04, 0x65,
+            0xe6, 0x8b, 0x9d, 0x57, 0xc2, 0x0d, 0xac, 0xbc,
+            0x49, 0xca, 0x9c, 0xcc, 0xf1, 0x79, 0xb6, 0x45,
+            0x99, 0x16, 0x64, 0xb3, 0x9d, 0x77, 0xef, 0x31,
+            0x7c, 0x71, 0xb8, 0x45, 0xb1, 0xe3, 0x0b, 0xd5,
+            0x09, 0x11, 0x20, 0x41, 0xd3, 0xa1, 0x97, 0x83,
+        ];
+
+        assert_eq!((out), (expected));
+    }
+
+    #[test]
+    fn rfc7914_password_nacl_80000_iterations_64_bytes() {
+        let mut out = [0u8; 64];
+        pbkdf2_hmac_sha256(b"Password", b"NaCl", 80000, &mut out);
+
+        let expected = [
+            0x4d, 0xdc, 0xd8, 0xf6, 0x0b, 0x98, 0xbe, 0x21,
+            0x83, 0x0c, 0xee, 0x5e, 0xf2, 0x27, 0x01, 0xf9,
+            0x64, 0x1a, 0x44, 0x18, 0xd0, 0x4c, 0x04, 0x14,
+            0xae, 0xff, 0x08, 0x87, 0x6b, 0x34, 0xab, 0x56,
+            0xa1, 0xd4, 0x25, 0xa1, 0x22, 0x58, 0x33, 0x54,
+            0x9a, 0xdb, 0x84, 0x1b, 0x51, 0xc9, 0xb3, 0x17,
+            0x6a, 0x27, 0x2b, 0xde, 0xbb, 0xa1, 0xd0, 0x78,
+            0x47, 0x8f, 0x62, 0xb3, 0x97, 0xf3, 0x3c, 0x8d,
+        ];
+
+        assert_eq!((out), (expected));
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn block_count_stops_at_the_largest_32_bit_block_index() {
+        let max = 32 * u32::MAX as usize;
+
+        assert_eq!((block_count(0)), (Some(0)));
+        assert_eq!((block_count(33)), (Some(2)));
+        assert_eq!((block_count(max)), (Some(u32::MAX)));
+        assert_eq!((block_count(max + 1)), (None));
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_iterations_panics() {
+        let mut out = [0u8; 32];
+        pbkdf2_hmac_sha256(b"password", b"salt", 0, &mut out);
+    }
+}
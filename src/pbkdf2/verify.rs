@@ -0,0 +1,70 @@
+use crate::pbkdf2::pbkdf2_hmac_sha256;
+use crate::pbkdf2::pbkdf2::block_count;
+use crate::utils::ct_eq;
+
+/// Check a password against a stored PBKDF2-HMAC-SHA-256 derived key.
+///
+/// # Arguments
+/// - `password`: Candidate password.
+/// - `salt`: Salt stored with the derived key.
+/// - `iterations`: Iteration count stored with the derived key.
+/// - `expected`: Stored derived key; its length is the `dkLen` to recompute.
+///
+/// # Description
+/// - Recompute the derived key with the same parameters.
+/// - Compare both keys in constant time.
+///
+/// # Returns
+/// `true` if the password matches. An empty `expected`, one longer than
+/// PBKDF2 can derive, or a zero `iterations` never matches.
+pub fn verify(
+    password: &[u8], 
+    salt: &[u8], 
+    iterations: u32, 
+    expected: &[u8],
+) -> bool {
+    if expected.is_empty() || iterations == 0 || block_count(expected.len()).is_none() {
+        return false;
+    }
+
+    let mut derived = vec![0u8; expected.len()];
+    pbkdf2_hmac_sha256(password, salt, iterations, &mut derived);
+
+    ct_eq(&derived, expected)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn accepts_the_right_password() {
+        let mut stored = [0u8; 32];
+        pbkdf2_hmac_sha256(b"correct horse", b"pepper", 100, &mut stored);
+
+        assert!(verify(b"correct horse", b"pepper", 100, &stored));
+    }
+
+    #[test]
+    fn rejects_wrong_password_salt_or_iterations() {
+        let mut stored = [0u8; 32];
+        pbkdf2_hmac_sha256(b"correct horse", b"pepper", 100, &mut stored);
+
+        assert!(!verify(b"correct h0rse", b"pepper", 100, &stored));
+        assert!(!verify(b"correct horse", b"salt", 100, &stored));
+        assert!(!verify(b"correct horse", b"pepper", 99, &stored));
+    }
+
+    #[test]
+    fn rejects_empty_expected_key() {
+        assert!(!verify(b"password", b"salt", 1, &[]));
+    }
+
+    #[test]
+    fn rejects_zero_iterations() {
+        let mut stored = [0u8; 32];
+        pbkdf2_hmac_sha256(b"password", b"salt", 1, &mut stored);
+
+        assert!(!verify(b"password", b"salt", 0, &stored));
+    }
+}
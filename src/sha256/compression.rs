@@ -1,5 +1,33 @@
 use crate::utils::{z, big_sigma1, big_sigma0, ch, maj};
-use crate::sha256::to_bytes;
+use crate::sha256::schedule::expand;
+#[cfg(target_arch = "x86_64")]
+use crate::sha256::shani;
+
+/// Initial hash values H(0) for SHA-256 (FIPS 180-4, section 5.3.3).
+pub const H0: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 
+    0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+/// Round constants.
+pub(crate) const K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 
+    0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5, 
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 
+    0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174, 
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 
+    0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da, 
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 
+    0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967, 
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 
+    0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85, 
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 
+    0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070, 
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 
+    0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3, 
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 
+    0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
 
 /// SHA256 compression function for message digestion.
 ///
@@ -8,91 +36,107 @@ use crate::sha256::to_bytes;
 /// Then takes each scheduled block and compression starts.
 ///
 /// # Description
-/// - Initialize (a, b, c, d, e, f, g, h), with the eight working variables; 
-///   (h0, h1, h2, h3, h4, h5, h6, h7), with the (m) hash value.
-/// - Implement all round operations (Ch, Maj, Big_sigma1, Big_sigma_0).
-/// - Compute the i-th intermediate hash value H(i)
+/// - Initialize the hash value with H(0).
+/// - Run the round function (`rounds`) once per scheduled block.
 ///
 /// # Returns
 /// Final 8-word digest as [u32; 8].
 pub fn compress(schedule: Vec<[u32; 64]>) -> [u32; 8] {
-    let mut digest = [0u32; 8];
-    // Hash values.
-    let mut h0: u32 = 0x6a09e667;
-    let mut h1: u32 = 0xbb67ae85;
-    let mut h2: u32 = 0x3c6ef372;
-    let mut h3: u32 = 0xa54ff53a;
-    let mut h4: u32 = 0x510e527f;
-    let mut h5: u32 = 0x9b05688c;
-    let mut h6: u32 = 0x1f83d9ab;
-    let mut h7: u32 = 0x5be0cd19;
-
-    // Round constants.
-    const K: [u32; 64] = [
-        0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 
-        0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5, 
-        0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 
-        0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174, 
-        0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 
-        0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da, 
-        0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 
-        0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967, 
-        0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 
-        0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85, 
-        0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 
-        0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070, 
-        0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 
-        0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3, 
-        0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 
-        0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
-    ];
+    let mut digest = H0;
 
     for m in schedule {
-        // Initialize working variables.
-        let mut a = h0;
-        let mut b = h1;
-        let mut c = h2;
-        let mut d = h3;
-        let mut e = h4;
-        let mut f = h5;
-        let mut g = h6;
-        let mut h = h7;
-        
-        // Implement round operations.
-        for i in 0..64 {
-            let t1 = z(z(z(z(h, big_sigma1(e)), ch(e, f, g)), K[i]), m[i]);
-            let t2 = z(big_sigma0(a), maj(a, b, c));
-            h = g;
-            g = f;
-            f = e;
-            e = z(d, t1);
-            d = c;
-            c = b;
-            b = a;
-            a = z(t1, t2);
-        }
-        
-        // Compute the i-th intermediate hash value H(i)
-        h0 = z(h0, a);
-        h1 = z(h1, b);
-        h2 = z(h2, c);
-        h3 = z(h3, d);
-        h4 = z(h4, e);
-        h5 = z(h5, f);
-        h6 = z(h6, g);
-        h7 = z(h7, h);
-        
-        // Digested state.
-        let d = [h0, h1, h2, h3, h4, h5, h6, h7];
-        digest = d;
+        rounds(&mut digest, &m);
     }
     digest
 }
 
+/// SHA256 compression of a single block on a caller-supplied state.
+///
+/// # Arguments
+/// - `state`: Chaining value H(i-1); `H0` for the first block of a message,
+///   or any midstate / custom IV.
+/// - `block`: One 512-bit block as 16 × 32-bit big-endian words.
+///
+/// # Description
+/// - Expand the block into its 64-word message schedule.
+/// - Run the round function and add the result into `state`, leaving H(i).
+///
+/// No padding is applied, callers hashing a full message must pad it first.
+///
+/// On `x86_64` CPUs with the SHA extensions this runs the hardware rounds 
+/// (`shani::compress_block`), detected at runtime; otherwise it falls back 
+/// to the portable round loop. Both give the same result.
+pub fn compress_block(state: &mut [u32; 8], block: &[u32; 16]) {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if shani::is_supported() {
+            // Safety: the required CPU features were just detected.
+            unsafe { shani::compress_block(state, block) };
+            return;
+        }
+    }
+
+    compress_block_portable(state, block);
+}
+
+/// Portable SHA256 compression of a single block, the fallback of 
+/// `compress_block`.
+pub(crate) fn compress_block_portable(state: &mut [u32; 8], block: &[u32; 16]) {
+    let m = expand(block);
+    rounds(state, &m);
+}
+
+/// SHA256 round function for one scheduled block.
+///
+/// # Arguments
+/// - `state`: Intermediate hash value H(i-1), updated in place to H(i).
+/// - `m`: The 64-word message schedule of the block.
+///
+/// # Description
+/// - Initialize (a, b, c, d, e, f, g, h), with the eight working variables; 
+///   (h0, h1, h2, h3, h4, h5, h6, h7), with the (m) hash value.
+/// - Implement all round operations (Ch, Maj, Big_sigma1, Big_sigma_0).
+/// - Compute the i-th intermediate hash value H(i)
+fn rounds(state: &mut [u32; 8], m: &[u32; 64]) {
+    // Initialize working variables.
+    let mut a = state[0];
+    let mut b = state[1];
+    let mut c = state[2];
+    let mut d = state[3];
+    let mut e = state[4];
+    let mut f = state[5];
+    let mut g = state[6];
+    let mut h = state[7];
+    
+    // Implement round operations.
+    for i in 0..64 {
+        let t1 = z(z(z(z(h, big_sigma1(e)), ch(e, f, g)), K[i]), m[i]);
+        let t2 = z(big_sigma0(a), maj(a, b, c));
+        h = g;
+        g = f;
+        f = e;
+        e = z(d, t1);
+        d = c;
+        c = b;
+        b = a;
+        a = z(t1, t2);
+    }
+    
+    // Compute the i-th intermediate hash value H(i)
+    state[0] = z(state[0], a);
+    state[1] = z(state[1], b);
+    state[2] = z(state[2], c);
+    state[3] = z(state[3], d);
+    state[4] = z(state[4], e);
+    state[5] = z(state[5], f);
+    state[6] = z(state[6], g);
+    state[7] = z(state[7], h);
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::sha256::{pars, padd, sched};
+    use crate::sha256::{pars, padd, sched, to_bytes};
 
     #[test]
     fn test_empty_string_compute_digested_array() {
@@ -158,6 +202,38 @@ mod test {
         assert_eq!((result), (expected));
     }
 
+    #[test]
+    fn compress_block_from_h0_matches_compress_for_abc() {
+        let blocks = pars(padd(b"abc"));
+        let mut state = H0;
+        compress_block(&mut state, &blocks[0]);
+
+        let result = state;
+        let expected = compress(sched(blocks));
+
+        assert_eq!((result), (expected));
+    }
+
+    #[test]
+    fn compress_block_resumes_from_midstate() {
+        let msg = b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq";
+        let blocks = pars(padd(msg));
+        let mut midstate = H0;
+        compress_block(&mut midstate, &blocks[0]);
+
+        // Continue from a copy of the cached midstate.
+        let mut state = midstate;
+        compress_block(&mut state, &blocks[1]);
+
+        let result = state;
+        let expected = [
+            0x248d6a61, 0xd20638b8, 0xe5c02693, 0x0c3e6039, 
+            0xa33ce459, 0x64ff2167, 0xf6ecedd4, 0x19db06c1,
+        ];
+
+        assert_eq!((result), (expected));
+    }
+
     #[test]
     fn compute_digest_to_32_bytes() {
         let msg = b"a".repeat(1_000_000);
@@ -176,4 +252,16 @@ mod test {
 
         assert_eq!((result), (expected));
     }
+
+    #[test]
+    fn portable_block_matches_compress_for_abc() {
+        let blocks = pars(padd(b"abc"));
+        let mut state = H0;
+        compress_block_portable(&mut state, &blocks[0]);
+
+        let result = state;
+        let expected = compress(sched(blocks));
+
+        assert_eq!((result), (expected));
+    }
 }
\ No newline at end of file
@@ -0,0 +1,193 @@
+use crate::sha256::compression::{H0, compress_block};
+use crate::sha256::padding::pad_final_blocks;
+use crate::sha256::parsing::parse_block;
+use crate::sha256::to_bytes;
+
+/// Incremental SHA-256 hasher.
+///
+/// # Description
+/// - Holds the eight-word chaining value and a 64-byte block buffer.
+/// - `update` can be called any number of times; every full block is
+///   compressed right away, so memory use does not grow with the message.
+/// - `finalize` pads the buffered tail and returns the 256-bit digest.
+///
+/// Produces the same digest as `sha256` for every split of the input.
+///
+/// # Reference
+/// [FIPS PUB 180-4](https://nvlpubs.nist.gov/nistpubs/fips/nist.fips.180-4.pdf)
+#[derive(Clone, Debug)]
+pub struct Sha256 {
+    state: [u32; 8],
+    buffer: [u8; 64],
+    buffer_len: usize,
+    msg_len: u64,
+}
+
+impl Sha256 {
+    /// Create a hasher initialized with the SHA-256 initial hash values.
+    pub fn new() -> Self {
+        Self::with_state(H0)
+    }
+
+    /// Create a hasher starting from the given initial hash value.
+    pub(crate) fn with_state(state: [u32; 8]) -> Self {
+        Sha256 {
+            state,
+            buffer: [0u8; 64],
+            buffer_len: 0,
+            msg_len: 0,
+        }
+    }
+
+    /// Absorb `msg` into the hash state.
+    ///
+    /// # Description
+    /// - Fill the pending buffer first, and compress it once it holds 64 bytes.
+    /// - Compress the remaining full blocks directly from `msg`.
+    /// - Keep the leftover tail (< 64 bytes) in the buffer.
+    pub fn update(&mut self, msg: &[u8]) {
+        self.msg_len = self.msg_len.wrapping_add(msg.len() as u64);
+        let mut i = 0;
+
+        // Top up a partially filled buffer.
+        if self.buffer_len > 0 {
+            while self.buffer_len < 64 && i < msg.len() {
+                self.buffer[self.buffer_len] = msg[i];
+                self.buffer_len += 1;
+                i += 1;
+            }
+            if self.buffer_len < 64 {
+                return;
+            }
+            let block = self.buffer;
+            self.process(&block);
+            self.buffer_len = 0;
+        }
+
+        // Full blocks straight from the input.
+        while msg.len() - i >= 64 {
+            let mut block = [0u8; 64];
+            block.copy_from_slice(&msg[i..i + 64]);
+            self.process(&block);
+            i += 64;
+        }
+
+        // Keep the tail for the next call.
+        while i < msg.len() {
+            self.buffer[self.buffer_len] = msg[i];
+            self.buffer_len += 1;
+            i += 1;
+        }
+    }
+
+    /// Pad the buffered tail and return the 32-byte digest.
+    ///
+    /// # Description
+    /// - Append a single `1` bit (0x80) after the buffered bytes.
+    /// - If fewer than 8 bytes remain for the length, zero-fill and compress
+    ///   an extra block.
+    /// - Append the message length in bits as a 64-bit big-endian integer.
+    pub fn finalize(self) -> [u8; 32] {
+        to_bytes(self.finalize_state())
+    }
+
+    /// Pad the buffered tail and return the final hash value as 8 words.
+    pub(crate) fn finalize_state(mut self) -> [u32; 8] {
+        let msg_as_bits = self.msg_len.wrapping_mul(8);
+        let (blocks, count) = pad_final_blocks(
+            &self.buffer[..self.buffer_len], 
+            msg_as_bits,
+        );
+        for block in &blocks[..count] {
+            self.process(block);
+        }
+
+        self.state
+    }
+
+    /// Parse, schedule and compress one 64-byte block into the state.
+    fn process(&mut self, block: &[u8; 64]) {
+        compress_block(&mut self.state, &parse_block(block));
+    }
+}
+
+impl Default for Sha256 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::sha256::sha256;
+
+    #[test]
+    fn empty_update_computes_empty_string_digest() {
+        let hasher = Sha256::new();
+
+        let result = hasher.finalize();
+        let expected = sha256(b"");
+
+        assert_eq!((result), (expected));
+    }
+
+    #[test]
+    fn single_update_matches_one_shot_for_abc() {
+        let mut hasher = Sha256::new();
+        hasher.update(b"abc");
+
+        let result = hasher.finalize();
+        let expected = [
+            0xba, 0x78, 0x16, 0xbf, 0x8f, 0x01, 0xcf, 0xea,
+            0x41, 0x41, 0x40, 0xde, 0x5d, 0xae, 0x22, 0x23,
+            0xb0, 0x03, 0x61, 0xa3, 0x96, 0x17, 0x7a, 0x9c,
+            0xb4, 0x10, 0xff, 0x61, 0xf2, 0x00, 0x15, 0xad,
+        ];
+
+        assert_eq!((result), (expected));
+    }
+
+    #[test]
+    fn every_split_of_the_input_matches_one_shot() {
+        let mut msg: Vec<u8> = Vec::new();
+        for i in 0..200u32 {
+            msg.push((i * 7 + 3) as u8);
+        }
+
+        for len in 0..msg.len() {
+            let expected = sha256(&msg[..len]);
+            for split in 0..=len {
+                let mut hasher = Sha256::new();
+                hasher.update(&msg[..split]);
+                hasher.update(&msg[split..len]);
+
+                assert_eq!((hasher.finalize()), (expected));
+            }
+        }
+    }
+
+    #[test]
+    fn one_million_a_in_uneven_chunks() {
+        let msg = b"a".repeat(1_000_000);
+        let mut hasher = Sha256::new();
+        let mut i = 0;
+        let mut step = 1;
+        while i < msg.len() {
+            let end = if i + step > msg.len() { msg.len() } else { i + step };
+            hasher.update(&msg[i..end]);
+            i = end;
+            step = step % 131 + 17;
+        }
+
+        let result = hasher.finalize();
+        let expected = [
+            0xcd, 0xc7, 0x6e, 0x5c, 0x99, 0x14, 0xfb, 0x92,
+            0x81, 0xa1, 0xc7, 0xe2, 0x84, 0xd7, 0x3e, 0x67,
+            0xf1, 0x80, 0x9a, 0x48, 0xa4, 0x97, 0x20, 0x0e,
+            0x04, 0x6d, 0x39, 0xcc, 0xc7, 0x11, 0x2c, 0xd0,
+        ];
+
+        assert_eq!((result), (expected));
+    }
+}
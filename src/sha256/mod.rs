@@ -1,13 +1,19 @@
 pub mod compression;
+pub mod hasher;
 pub mod padding;
 pub mod parsing;
 pub mod schedule;
 pub mod sha;
+pub mod sha224;
+#[cfg(target_arch = "x86_64")]
+pub(crate) mod shani;
 pub mod to_bytes;
 
-pub use compression::compress;
-pub use padding::padd;
+pub use compression::{compress, compress_block};
+pub use hasher::Sha256;
+pub use padding::{padd, padd_bits};
 pub use parsing::pars;
 pub use schedule::sched;
-pub use sha::sha256;
+pub use sha::{sha256, sha256_bits};
+pub use sha224::{sha224, Sha224};
 pub use to_bytes::to_bytes;
\ No newline at end of file
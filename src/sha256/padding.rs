@@ -12,27 +12,98 @@
 /// # Returns
 /// A `Vec<u8>` containing the padded message in bytes.
 pub fn padd(msg: &[u8]) -> Vec<u8> {
-    let mut bytes: Vec<u8> = msg.to_vec();
+    padd_bits(msg, (msg.len() as u64) * 8)
+}
+
+/// Pad a message of any bit length to a 512-bit block.
+///
+/// # Arguments
+/// - `msg`: Bytes holding the message bits, most significant bit first.
+/// - `bit_len`: Exact message length in bits (at most `msg.len() * 8`).
+///
+/// # Description
+/// - Keep the first `bit_len` bits of `msg`; unused low bits of a partial 
+///   last byte are cleared.
+/// - Append a single `1` bit right after the last message bit, which is 
+///   mid-byte when `bit_len` is not a multiple of 8.
+/// - Append `0` bits until the length is congruent to 448 (mod 512).  
+/// - Append `bit_len` as a 64-bit big-endian integer.  
+///
+/// # Returns
+/// A `Vec<u8>` containing the padded message in bytes.
+///
+/// # Panics
+/// If `bit_len` is greater than `msg.len() * 8`.
+///
+/// # Reference
+/// [FIPS PUB 180-4, section 5.1.1](https://nvlpubs.nist.gov/nistpubs/fips/nist.fips.180-4.pdf)
+pub fn padd_bits(msg: &[u8], bit_len: u64) -> Vec<u8> {
+    assert!(bit_len <= (msg.len() as u64) * 8, "bit length exceeds message");
+
+    let full_bytes = (bit_len / 8) as usize;
+    let tail_start = full_bytes - full_bytes % 64;
+    let tail_end = bit_len.div_ceil(8) as usize;
+    let mut bytes: Vec<u8> = msg[..tail_start].to_vec();
+
+    let (blocks, count) = pad_final_blocks(&msg[tail_start..tail_end], bit_len);
+    for block in &blocks[..count] {
+        bytes.extend_from_slice(block);
+    }
+
+    bytes
+}
+
+/// Build the one or two padded blocks that end a message.
+///
+/// # Arguments
+/// - `tail`: The message bytes after the last full block (under 64 whole
+///   bytes), plus the partial last byte when `bit_len` is not a multiple
+///   of 8.
+/// - `bit_len`: Exact length of the whole message in bits.
+///
+/// # Description
+/// - Copy the whole bytes of `tail`.
+/// - Append a single `1` bit right after the last message bit, which is 
+///   mid-byte when `bit_len` is not a multiple of 8; unused low bits of the
+///   partial byte are cleared.
+/// - If fewer than 8 bytes remain for the length, it goes in a second block.
+/// - Append `bit_len` as a 64-bit big-endian integer.
+///
+/// Shared by `padd_bits`, `sha256` and `Sha256::finalize`, so every path 
+/// pads the same way.
+///
+/// # Returns
+/// The blocks and how many of them (1 or 2) are used.
+pub(crate) fn pad_final_blocks(tail: &[u8], bit_len: u64) -> ([[u8; 64]; 2], usize) {
+    let extra_bits = (bit_len % 8) as u32;
+    let full_bytes = if extra_bits == 0 { tail.len() } else { tail.len() - 1 };
+    let mut out = [0u8; 128];
+    out[..full_bytes].copy_from_slice(&tail[..full_bytes]);
 
     // Append 1 + k.
-    bytes.push(0x80);
-    while bytes.len() % 64 != 56 {
-        bytes.push(0x00);
+    if extra_bits == 0 {
+        out[full_bytes] = 0x80;
+    } else {
+        // Keep the top `extra_bits` bits and set the bit right after them.
+        let mask = 0xFFu8 << (8 - extra_bits);
+        out[full_bytes] = (tail[full_bytes] & mask) | (0x80 >> extra_bits);
     }
+    // No room for the length, it goes in an extra block.
+    let count = if full_bytes >= 56 { 2 } else { 1 };
 
-    let msg_as_bits = (msg.len() as u64) * 8;
     let mut i = 0;
     // Append MSG length, in big endian order.
     while i < 8 {
         // Shift by (7 - i) * 8 to extract big-endian length bytes (MSB first).
         let decremental_shift = (7 - i) * 8;
-        let message = ((msg_as_bits >> decremental_shift) & 0xFF) as u8;
-
-        bytes.push(message);
+        out[count * 64 - 8 + i] = ((bit_len >> decremental_shift) & 0xFF) as u8;
         i += 1;
     }
 
-    bytes
+    let mut blocks = [[0u8; 64]; 2];
+    blocks[0].copy_from_slice(&out[..64]);
+    blocks[1].copy_from_slice(&out[64..]);
+    (blocks, count)
 }
 
 #[cfg(test)]
@@ -53,4 +124,36 @@ mod test {
         
         assert_eq!((result), (expected));
     }
+
+    #[test]
+    fn whole_bytes_pad_like_padd() {
+        let msg = b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq";
+
+        assert_eq!((padd_bits(msg, 56 * 8)), (padd(msg)));
+        assert_eq!((padd_bits(msg, 0)), (padd(b"")));
+    }
+
+    #[test]
+    fn places_one_bit_mid_byte_and_clears_unused_bits() {
+        // 5-bit message 01101, stored as 0x6f with junk in the low bits.
+        let result = padd_bits(&[0x6f], 5);
+
+        assert_eq!((result.len()), (64));
+        assert_eq!((result[0]), (0x6c));
+        assert_eq!((result[1..63].to_vec()), (vec![0u8; 62]));
+        assert_eq!((result[63]), (5));
+    }
+
+    #[test]
+    fn adds_second_block_when_length_does_not_fit() {
+        // 447 bits plus the 1 bit end exactly on bit 448; 448 bits do not.
+        assert_eq!((padd_bits(&[0xff; 56], 447).len()), (64));
+        assert_eq!((padd_bits(&[0xff; 56], 448).len()), (128));
+    }
+
+    #[test]
+    #[should_panic]
+    fn rejects_bit_length_past_the_buffer() {
+        padd_bits(&[0x00], 9);
+    }
 }
\ No newline at end of file
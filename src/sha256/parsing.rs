@@ -45,6 +45,22 @@ pub fn pars(bytes: Vec<u8>) -> Vec<[u32; 16]> {
     blocks
 }
 
+/// Parse one 64-byte block into 16 × 32-bit big-endian words.
+pub(crate) fn parse_block(bytes: &[u8; 64]) -> [u32; 16] {
+    let mut block = [0u32; 16];
+    let mut l = 0;
+    while l < 16 {
+        let j = l * 4;
+        block[l] = ((bytes[j] as u32) << 24)
+            | ((bytes[j + 1] as u32) << 16)
+            | ((bytes[j + 2] as u32) << 8)
+            | (bytes[j + 3] as u32);
+        l += 1;
+    }
+
+    block
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -17,23 +17,25 @@ pub fn sched(blocks: Vec<[u32; 16]>) -> Vec<[u32; 64]> {
     let mut schedule: Vec<[u32; 64]> = Vec::new();
 
     for block in blocks {
-        let mut m = [0u32; 64];
-        
-        for t in 0..16 {
-            m[t] = block[t];
-        }
-        
-        for t in 16..64 {
-            m[t] = z(
-                z(small_sigma1(m[t-2]), m[t-7]), 
-                z(small_sigma0(m[t-15]), m[t-16])
-            );
-        }
-        schedule.push(m);
+        schedule.push(expand(&block));
     }
     schedule
 }
 
+/// Expand a single 512-bit block into its 64-word message schedule.
+pub(crate) fn expand(block: &[u32; 16]) -> [u32; 64] {
+    let mut m = [0u32; 64];
+    m[..16].copy_from_slice(block);
+    
+    for t in 16..64 {
+        m[t] = z(
+            z(small_sigma1(m[t-2]), m[t-7]), 
+            z(small_sigma0(m[t-15]), m[t-16])
+        );
+    }
+    m
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -71,6 +73,7 @@ mod test {
     }
 
     #[test]
+    #[allow(clippy::manual_memcpy)]
     fn test_schedule_expansion_for_abc() {
         let msg = b"abc";
         let padding = padd(msg);
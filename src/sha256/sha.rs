@@ -1,4 +1,7 @@
-use crate::sha256::{pars, padd, sched, compress, to_bytes};
+use crate::sha256::compression::{H0, compress_block};
+use crate::sha256::padding::pad_final_blocks;
+use crate::sha256::parsing::parse_block;
+use crate::sha256::to_bytes;
 
 /// SHA-256: Pads, parses, schedules, and compresses a message into a 256-bit 
 /// hash.
@@ -7,13 +10,17 @@ use crate::sha256::{pars, padd, sched, compress, to_bytes};
 /// - `msg`: Message as a byte slice (`&[u8]`). 
 ///
 /// # Description
-/// - **Padding:** Appends a single '1' bit, then `k` zero bits so that the
-///   total length ≡ 448 (mod 512). Finally appends the original message
-///   length as a 64-bit big-endian integer.
-/// - **Parsing:** Splits the padded message into 512-bit blocks (16 × 32-bit words).
-/// - **Scheduling:** Expands each block into 64 words using the `σ0` and `σ1` functions.
-/// - **Compression:** Iteratively updates the hash state across all blocks
-///   to compute the final digest.
+/// - **Full blocks:** Every complete 64-byte block is copied one block at a
+///   time into a stack buffer, then parsed, scheduled and compressed, so the
+///   message is never copied as a whole.
+/// - **Padding:** The remaining bytes get a single '1' bit, then `k` zero 
+///   bits so that the total length ≡ 448 (mod 512), then the original 
+///   message length as a 64-bit big-endian integer. This builds one or two 
+///   final blocks on the stack, with the same helper as `padd_bits`.
+/// - **Compression:** Each block updates the hash state, so memory use does
+///   not grow with the message length.
+///
+/// Same digest as the `padd` → `pars` → `sched` → `compress` pipeline.
 ///
 /// # Returns
 /// A 32-byte array representing the 256-bit hash.
@@ -24,18 +31,66 @@ use crate::sha256::{pars, padd, sched, compress, to_bytes};
 /// # Reference
 /// [FIPS PUB 180-4](https://nvlpubs.nist.gov/nistpubs/fips/nist.fips.180-4.pdf)
 pub fn sha256(msg: &[u8]) -> [u8; 32] {
-    let padding = padd(msg);
-    let parsing = pars(padding);
-    let schedules = sched(parsing);
-    let digest = compress(schedules);
-    let bytes = to_bytes(digest);
+    let digest = digest_bits(msg, (msg.len() as u64).wrapping_mul(8));
+    to_bytes(digest)
+}
+
+/// SHA-256 of a message whose length is not necessarily a whole number of 
+/// bytes.
+///
+/// # Arguments
+/// - `msg`: Bytes holding the message bits, most significant bit first (the
+///   layout of the NIST CAVP bit-oriented test files).
+/// - `bit_len`: Exact message length in bits (at most `msg.len() * 8`).
+///
+/// # Description
+/// Same block-by-block path as `sha256`, padded like `padd_bits` so the `1`
+/// bit follows the last message bit. Equals `sha256(msg)` when `bit_len` is
+/// `msg.len() * 8`.
+///
+/// # Returns
+/// A 32-byte array representing the 256-bit hash.
+///
+/// # Panics
+/// If `bit_len` is greater than `msg.len() * 8`.
+///
+/// # Reference
+/// [FIPS PUB 180-4](https://nvlpubs.nist.gov/nistpubs/fips/nist.fips.180-4.pdf)
+pub fn sha256_bits(msg: &[u8], bit_len: u64) -> [u8; 32] {
+    assert!(bit_len <= (msg.len() as u64) * 8, "bit length exceeds message");
+
+    let digest = digest_bits(msg, bit_len);
+    to_bytes(digest)
+}
+
+/// Hash the first `bit_len` bits of `msg` into the final eight state words.
+fn digest_bits(msg: &[u8], bit_len: u64) -> [u32; 8] {
+    let full_bytes = (bit_len / 8) as usize;
+    let mut state = H0;
+    let mut block = [0u8; 64];
+    let mut i = 0;
+
+    // Full blocks, one at a time through the stack buffer.
+    while full_bytes - i >= 64 {
+        block.copy_from_slice(&msg[i..i + 64]);
+        compress_block(&mut state, &parse_block(&block));
+        i += 64;
+    }
+
+    // Tail of the message, padded like `padd_bits`.
+    let tail_end = bit_len.div_ceil(8) as usize;
+    let (blocks, count) = pad_final_blocks(&msg[i..tail_end], bit_len);
+    for block in &blocks[..count] {
+        compress_block(&mut state, &parse_block(block));
+    }
 
-    bytes
+    state
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::sha256::{compress, padd, padd_bits, pars, sched};
 
     #[test]
     fn test_empty_string_and_digest() {
@@ -92,4 +147,131 @@ mod test {
 
         assert_eq!((result), (expected));
     }
-}
\ No newline at end of file
+    #[test]
+    fn one_bit_zero_message_and_digest() {
+        // Len = 1 from the CAVP bit-oriented short messages.
+        let msg = [0x00];
+        let result = sha256_bits(&msg, 1);
+        let expected = [
+            0xbd, 0x4f, 0x9e, 0x98, 0xbe, 0xb6, 0x8c, 0x6e,
+            0xad, 0x32, 0x43, 0xb1, 0xb4, 0xc7, 0xfe, 0xd7,
+            0x5f, 0xa4, 0xfe, 0xaa, 0xb1, 0xf8, 0x47, 0x95,
+            0xcb, 0xd8, 0xa9, 0x86, 0x76, 0xa2, 0xa3, 0x75,
+        ];
+
+        assert_eq!((result), (expected));
+    }
+
+    #[test]
+    fn five_bit_message_and_digest() {
+        // Five-bit message 01101 from the NIST SHA-256 examples.
+        let msg = [0x68];
+        let result = sha256_bits(&msg, 5);
+        let expected = [
+            0xd6, 0xd3, 0xe0, 0x2a, 0x31, 0xa8, 0x4a, 0x8c,
+            0xaa, 0x97, 0x18, 0xed, 0x6c, 0x20, 0x57, 0xbe,
+            0x09, 0xdb, 0x45, 0xe7, 0x82, 0x3e, 0xb5, 0x07,
+            0x9c, 0xe7, 0xa5, 0x73, 0xa3, 0x76, 0x0f, 0x95,
+        ];
+
+        assert_eq!((result), (expected));
+    }
+
+    #[test]
+    fn seventeen_bit_message_and_digest() {
+        let msg = [0x9f, 0xff, 0xff];
+        let result = sha256_bits(&msg, 17);
+        let expected = [
+            0x23, 0xbc, 0x47, 0x18, 0x54, 0x18, 0x54, 0xc9,
+            0xfb, 0xb0, 0x63, 0x63, 0x75, 0x8f, 0x51, 0x44,
+            0x2d, 0x81, 0x25, 0xd2, 0xf7, 0x37, 0x84, 0xde,
+            0xcb, 0x6b, 0x32, 0x59, 0x44, 0x3d, 0x64, 0x95,
+        ];
+
+        assert_eq!((result), (expected));
+    }
+
+    #[test]
+    fn four_hundred_forty_one_bit_message_and_digest() {
+        // Padding still fits in the first block.
+        let mut msg = [0u8; 56];
+        for (i, byte) in msg.iter_mut().enumerate() {
+            *byte = (i + 1) as u8;
+        }
+        let result = sha256_bits(&msg, 441);
+        let expected = [
+            0xa1, 0x65, 0xe9, 0xda, 0x5c, 0xe8, 0xf3, 0x0a,
+            0xfc, 0xcb, 0x0a, 0x12, 0x53, 0x29, 0x23, 0x95,
+            0x2a, 0x04, 0x52, 0x09, 0x91, 0xd5, 0x35, 0x06,
+            0xc6, 0x1d, 0x3c, 0xe5, 0x59, 0xf6, 0xba, 0x1d,
+        ];
+
+        assert_eq!((result), (expected));
+    }
+
+    #[test]
+    fn five_hundred_eleven_bit_message_and_digest() {
+        // Padding spills into a second block.
+        let msg = [0xffu8; 64];
+        let result = sha256_bits(&msg, 511);
+        let expected = [
+            0x72, 0xc1, 0x0a, 0x55, 0x40, 0x47, 0xe0, 0xb0,
+            0x19, 0x56, 0xca, 0x3c, 0x5c, 0x2f, 0x4e, 0x96,
+            0x8b, 0x78, 0xff, 0x42, 0x7e, 0x3c, 0x90, 0x47,
+            0x74, 0xd5, 0x1c, 0x10, 0x45, 0x44, 0x7a, 0x40,
+        ];
+
+        assert_eq!((result), (expected));
+    }
+
+    #[test]
+    fn whole_byte_bit_length_matches_sha256() {
+        let msg = b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq";
+
+        for len in 0..msg.len() {
+            let result = sha256_bits(&msg[..len], len as u64 * 8);
+
+            assert_eq!((result), (sha256(&msg[..len])));
+        }
+    }
+
+    #[test]
+    fn ignores_bits_past_the_bit_length() {
+        let result = sha256_bits(&[0x9f, 0xff, 0xff], 17);
+        let expected = sha256_bits(&[0x9f, 0xff, 0x80], 17);
+
+        assert_eq!((result), (expected));
+    }
+
+    #[test]
+    fn matches_padded_pipeline_around_block_boundaries() {
+        let mut msg: Vec<u8> = Vec::new();
+        for i in 0..300u32 {
+            msg.push((i * 31 + 7) as u8);
+        }
+
+        for len in 0..msg.len() {
+            let pipeline = to_bytes(compress(sched(pars(padd(&msg[..len])))));
+
+            assert_eq!((sha256(&msg[..len])), (pipeline));
+        }
+    }
+
+    #[test]
+    fn bit_lengths_match_padded_pipeline() {
+        let msg = [0xa5u8; 130];
+
+        for bit_len in 0..(130 * 8) as u64 {
+            let padded = padd_bits(&msg, bit_len);
+            let pipeline = to_bytes(compress(sched(pars(padded))));
+
+            assert_eq!((sha256_bits(&msg, bit_len)), (pipeline));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn bits_rejects_bit_length_past_the_message() {
+        sha256_bits(&[0x00], 9);
+    }
+}
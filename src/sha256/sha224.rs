@@ -0,0 +1,239 @@
+use crate::sha256::Sha256;
+
+/// Initial hash values H(0) for SHA-224 (FIPS 180-4, section 5.3.2).
+pub const H0_224: [u32; 8] = [
+    0xc1059ed8, 0x367cd507, 0x3070dd17, 0xf70e5939, 
+    0xffc00b31, 0x68581511, 0x64f98fa7, 0xbefa4fa4,
+];
+
+/// Incremental SHA-224 hasher.
+///
+/// # Description
+/// - Runs the SHA-256 pipeline starting from the SHA-224 initial hash values.
+/// - Truncates the final 8-word hash value to its leftmost 7 words.
+///
+/// # Reference
+/// [FIPS PUB 180-4](https://nvlpubs.nist.gov/nistpubs/fips/nist.fips.180-4.pdf)
+#[derive(Clone, Debug)]
+pub struct Sha224 {
+    inner: Sha256,
+}
+
+impl Sha224 {
+    /// Create a hasher initialized with the SHA-224 initial hash values.
+    pub fn new() -> Self {
+        Sha224 { inner: Sha256::with_state(H0_224) }
+    }
+
+    /// Absorb `msg` into the hash state.
+    pub fn update(&mut self, msg: &[u8]) {
+        self.inner.update(msg);
+    }
+
+    /// Pad the buffered tail and return the 28-byte digest.
+    pub fn finalize(self) -> [u8; 28] {
+        let digest = self.inner.finalize_state();
+        let mut outp = [0u8; 28];
+        let mut i = 0;
+
+        // Keep words H0..H6, big-endian.
+        while i < 7 {
+            let word = digest[i];
+            let j = i * 4;
+
+            outp[j] =     (word >> 24) as u8;
+            outp[j + 1] = (word >> 16) as u8;
+            outp[j + 2] = (word >> 8)  as u8;
+            outp[j + 3] = (word)       as u8;
+
+            i += 1;
+        }
+
+        outp
+    }
+}
+
+impl Default for Sha224 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// SHA-224: SHA-256 with different initial hash values and a 224-bit output.
+///
+/// # Arguments
+/// - `msg`: Message as a byte slice (`&[u8]`).
+///
+/// # Returns
+/// A 28-byte array representing the 224-bit hash.
+///
+/// # Reference
+/// [FIPS PUB 180-4](https://nvlpubs.nist.gov/nistpubs/fips/nist.fips.180-4.pdf)
+pub fn sha224(msg: &[u8]) -> [u8; 28] {
+    let mut hasher = Sha224::new();
+    hasher.update(msg);
+    hasher.finalize()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn use_one_word_and_digest() {
+        let result = sha224(b"abc");
+        let expected = [
+            0x23, 0x09, 0x7d, 0x22, 0x34, 0x05, 0xd8, 0x22,
+            0x86, 0x42, 0xa4, 0x77, 0xbd, 0xa2, 0x55, 0xb3,
+            0x2a, 0xad, 0xbc, 0xe4, 0xbd, 0xa0, 0xb3, 0xf7,
+            0xe3, 0x6c, 0x9d, 0xa7,
+        ];
+
+        assert_eq!((result), (expected));
+    }
+
+    #[test]
+    fn expanding_in_second_block_and_digest() {
+        let msg = b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq";
+        let result = sha224(msg);
+        let expected = [
+            0x75, 0x38, 0x8b, 0x16, 0x51, 0x27, 0x76, 0xcc,
+            0x5d, 0xba, 0x5d, 0xa1, 0xfd, 0x89, 0x01, 0x50,
+            0xb0, 0xc6, 0x45, 0x5c, 0xb4, 0xf5, 0x8b, 0x19,
+            0x52, 0x52, 0x25, 0x25,
+        ];
+
+        assert_eq!((result), (expected));
+    }
+
+    #[test]
+    fn test_one_million_a_and_digest() {
+        let msg = b"a".repeat(1_000_000);
+        let result = sha224(&msg);
+        let expected = [
+            0x20, 0x79, 0x46, 0x55, 0x98, 0x0c, 0x91, 0xd8,
+            0xbb, 0xb4, 0xc1, 0xea, 0x97, 0x61, 0x8a, 0x4b,
+            0xf0, 0x3f, 0x42, 0x58, 0x19, 0x48, 0xb2, 0xee,
+            0x4e, 0xe7, 0xad, 0x67,
+        ];
+
+        assert_eq!((result), (expected));
+    }
+
+    #[test]
+    fn cavp_short_msg_len_8() {
+        let result = sha224(&[0x84]);
+        let expected = [
+            0x3c, 0xd3, 0x69, 0x21, 0xdf, 0x5d, 0x69, 0x63,
+            0xe7, 0x37, 0x39, 0xcf, 0x4d, 0x20, 0x21, 0x1e,
+            0x2d, 0x88, 0x77, 0xc1, 0x9c, 0xff, 0x08, 0x7a,
+            0xde, 0x9d, 0x0e, 0x3a,
+        ];
+
+        assert_eq!((result), (expected));
+    }
+
+    #[test]
+    fn cavp_short_msg_len_16() {
+        let result = sha224(&[0x5c, 0x7b]);
+        let expected = [
+            0xda, 0xff, 0x9b, 0xce, 0x68, 0x5e, 0xb8, 0x31,
+            0xf9, 0x7f, 0xc1, 0x22, 0x5b, 0x03, 0xc2, 0x75,
+            0xa6, 0xc1, 0x12, 0xe2, 0xd6, 0xe7, 0x6f, 0x5f,
+            0xaf, 0x7a, 0x36, 0xe6,
+        ];
+
+        assert_eq!((result), (expected));
+    }
+
+    #[test]
+    fn cavp_short_msg_len_24() {
+        let result = sha224(&[0x51, 0xca, 0x3d]);
+        let expected = [
+            0x2c, 0x89, 0x59, 0x02, 0x35, 0x15, 0x47, 0x6e,
+            0x38, 0x38, 0x8a, 0xbb, 0x43, 0x59, 0x9a, 0x29,
+            0x87, 0x6b, 0x4b, 0x33, 0xd5, 0x6a, 0xdc, 0x06,
+            0x03, 0x2d, 0xe3, 0xa2,
+        ];
+
+        assert_eq!((result), (expected));
+    }
+
+    // Self-generated multi-block cross-checks, NOT the NIST CAVP 
+    // SHA224LongMsg.rsp vectors: the message bytes are made up 
+    // (byte i = i * 31 + 7) and the expected digests were computed with 
+    // Python's hashlib. Only the bit lengths follow the CAVP file.
+    fn generated_msg(bit_len: usize) -> Vec<u8> {
+        let mut msg = Vec::with_capacity(bit_len / 8);
+        for i in 0..bit_len / 8 {
+            msg.push((i * 31 + 7) as u8);
+        }
+        msg
+    }
+
+    #[test]
+    fn generated_multi_block_msg_len_1304_matches_hashlib() {
+        let result = sha224(&generated_msg(1304));
+        let expected = [
+            0x50, 0xf3, 0x80, 0xd6, 0xc7, 0xf4, 0xf3, 0x1c,
+            0xfe, 0x6b, 0x2a, 0x88, 0xf4, 0x6e, 0x7e, 0xac,
+            0x86, 0x4d, 0x3b, 0x97, 0xb1, 0x87, 0x18, 0x20,
+            0x6b, 0xf6, 0xdd, 0x10,
+        ];
+
+        assert_eq!((result), (expected));
+    }
+
+    #[test]
+    fn generated_multi_block_msg_len_2608_matches_hashlib() {
+        let result = sha224(&generated_msg(2608));
+        let expected = [
+            0x26, 0xef, 0x37, 0x96, 0xf5, 0x82, 0x56, 0xf3,
+            0x05, 0x1f, 0x9a, 0x7d, 0x82, 0x73, 0x11, 0xb6,
+            0x2f, 0xc9, 0xf6, 0x6c, 0x9a, 0x5d, 0x9d, 0xe6,
+            0x40, 0x8f, 0x68, 0x1e,
+        ];
+
+        assert_eq!((result), (expected));
+    }
+
+    #[test]
+    fn generated_multi_block_msg_len_10432_matches_hashlib() {
+        let result = sha224(&generated_msg(10432));
+        let expected = [
+            0xd7, 0x8e, 0xbe, 0xf0, 0xdb, 0xf8, 0xfa, 0x36,
+            0xac, 0x98, 0x3c, 0xff, 0xcc, 0xf8, 0x77, 0x0c,
+            0x4a, 0x0e, 0x24, 0x4e, 0x06, 0x8f, 0x47, 0x29,
+            0xd8, 0xcf, 0x69, 0x5e,
+        ];
+
+        assert_eq!((result), (expected));
+    }
+
+    #[test]
+    fn generated_multi_block_msg_len_52160_matches_hashlib() {
+        let result = sha224(&generated_msg(52160));
+        let expected = [
+            0xfb, 0x91, 0xcc, 0xbe, 0x4c, 0x1d, 0x9d, 0xac,
+            0xf8, 0x01, 0xde, 0x2a, 0xdb, 0x02, 0xc1, 0xbb,
+            0x1d, 0x03, 0x5d, 0x33, 0xba, 0x08, 0x4a, 0x66,
+            0x89, 0x22, 0x20, 0x97,
+        ];
+
+        assert_eq!((result), (expected));
+    }
+
+    #[test]
+    fn streaming_matches_one_shot_across_block_boundary() {
+        let msg = b"a".repeat(1_000);
+        let mut hasher = Sha224::new();
+        hasher.update(&msg[..63]);
+        hasher.update(&msg[63..130]);
+        hasher.update(&msg[130..]);
+
+        let result = hasher.finalize();
+        let expected = sha224(&msg);
+
+        assert_eq!((result), (expected));
+    }
+}
@@ -0,0 +1,154 @@
+use core::arch::x86_64::{
+    __m128i, _mm_add_epi32, _mm_alignr_epi8, _mm_blend_epi16, _mm_loadu_si128,
+    _mm_sha256msg1_epu32, _mm_sha256msg2_epu32, _mm_sha256rnds2_epu32,
+    _mm_shuffle_epi32, _mm_storeu_si128,
+};
+
+use crate::sha256::compression::K;
+
+/// `true` if the CPU has the SHA extensions and the SSE levels they need.
+///
+/// The standard library caches the CPUID result, so this is cheap enough to
+/// check on every block.
+pub(crate) fn is_supported() -> bool {
+    is_x86_feature_detected!("sha")
+        && is_x86_feature_detected!("sse2")
+        && is_x86_feature_detected!("ssse3")
+        && is_x86_feature_detected!("sse4.1")
+}
+
+/// SHA256 compression of a single block with the x86 SHA extensions.
+///
+/// # Arguments
+/// - `state`: Chaining value H(i-1), updated in place to H(i).
+/// - `block`: One 512-bit block as 16 × 32-bit words (already parsed).
+///
+/// # Description
+/// - Repack the state into the (A, B, E, F) / (C, D, G, H) register layout
+///   used by `sha256rnds2`.
+/// - Run 16 groups of 4 rounds: `sha256rnds2` does 2 rounds per call, and
+///   from group 4 on `sha256msg1` / `sha256msg2` compute the next 4 schedule
+///   words from the previous 16.
+/// - Add the saved input state and unpack back to (A, ..., H).
+///
+/// Same result as the portable round loop.
+///
+/// # Safety
+/// The CPU must support SHA, SSE2, SSSE3 and SSE4.1 (see `is_supported`).
+///
+/// # Reference
+/// [Intel SHA Extensions](https://www.intel.com/content/www/us/en/developer/articles/technical/intel-sha-extensions.html)
+#[target_feature(enable = "sha,sse2,ssse3,sse4.1")]
+pub(crate) unsafe fn compress_block(state: &mut [u32; 8], block: &[u32; 16]) {
+    let state_ptr = state.as_ptr() as *const __m128i;
+    let block_ptr = block.as_ptr() as *const __m128i;
+
+    // Lanes are listed from lane 3 down to lane 0.
+    // (D, C, B, A) and (H, G, F, E) -> (A, B, E, F) and (C, D, G, H).
+    let dcba = unsafe { _mm_loadu_si128(state_ptr) };
+    let hgfe = unsafe { _mm_loadu_si128(state_ptr.add(1)) };
+    let cdab = _mm_shuffle_epi32(dcba, 0xB1);
+    let efgh = _mm_shuffle_epi32(hgfe, 0x1B);
+    let mut abef = _mm_alignr_epi8(cdab, efgh, 8);
+    let mut cdgh = _mm_blend_epi16(efgh, cdab, 0xF0);
+    let abef_save = abef;
+    let cdgh_save = cdgh;
+
+    // The last 16 schedule words, 4 per register.
+    let mut w: [__m128i; 4] = unsafe {
+        [
+            _mm_loadu_si128(block_ptr),
+            _mm_loadu_si128(block_ptr.add(1)),
+            _mm_loadu_si128(block_ptr.add(2)),
+            _mm_loadu_si128(block_ptr.add(3)),
+        ]
+    };
+
+    let mut i = 0;
+    while i < 16 {
+        if i >= 4 {
+            // W[t] from W[t-16], W[t-15], W[t-7] and W[t-2], four at once.
+            let t1 = _mm_sha256msg1_epu32(w[i % 4], w[(i + 1) % 4]);
+            let t2 = _mm_alignr_epi8(w[(i + 3) % 4], w[(i + 2) % 4], 4);
+            let t3 = _mm_add_epi32(t1, t2);
+            w[i % 4] = _mm_sha256msg2_epu32(t3, w[(i + 3) % 4]);
+        }
+
+        let k = unsafe { _mm_loadu_si128(K.as_ptr().add(4 * i) as *const __m128i) };
+        let wk = _mm_add_epi32(w[i % 4], k);
+        cdgh = _mm_sha256rnds2_epu32(cdgh, abef, wk);
+        // Move W[t+2] + K[t+2], W[t+3] + K[t+3] into the low lanes.
+        let wk_high = _mm_shuffle_epi32(wk, 0x0E);
+        abef = _mm_sha256rnds2_epu32(abef, cdgh, wk_high);
+        i += 1;
+    }
+
+    abef = _mm_add_epi32(abef, abef_save);
+    cdgh = _mm_add_epi32(cdgh, cdgh_save);
+
+    // (A, B, E, F) and (C, D, G, H) -> (D, C, B, A) and (H, G, F, E).
+    let feba = _mm_shuffle_epi32(abef, 0x1B);
+    let dchg = _mm_shuffle_epi32(cdgh, 0xB1);
+    let dcba = _mm_blend_epi16(feba, dchg, 0xF0);
+    let hgef = _mm_alignr_epi8(dchg, feba, 8);
+
+    let out_ptr = state.as_mut_ptr() as *mut __m128i;
+    unsafe {
+        _mm_storeu_si128(out_ptr, dcba);
+        _mm_storeu_si128(out_ptr.add(1), hgef);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::next;
+    use crate::sha256::compression::{compress_block_portable, H0};
+
+    #[test]
+    fn matches_portable_on_random_states_and_blocks() {
+        if !is_supported() {
+            return;
+        }
+
+        let mut seed = 0x2545_f491_4f6c_dd1du64;
+        for _ in 0..10_000 {
+            let mut state = [0u32; 8];
+            let mut block = [0u32; 16];
+            for word in state.iter_mut() {
+                *word = next(&mut seed) as u32;
+            }
+            for word in block.iter_mut() {
+                *word = next(&mut seed) as u32;
+            }
+
+            let mut expected = state;
+            compress_block_portable(&mut expected, &block);
+            let mut result = state;
+            unsafe { compress_block(&mut result, &block) };
+
+            assert_eq!((result), (expected));
+        }
+    }
+
+    #[test]
+    fn matches_portable_for_abc_block() {
+        if !is_supported() {
+            return;
+        }
+
+        // "abc" padded to one block.
+        let mut block = [0u32; 16];
+        block[0] = 0x61626380;
+        block[15] = 24;
+        let mut result = H0;
+        unsafe { compress_block(&mut result, &block) };
+
+        let expected = [
+            0xba7816bf, 0x8f01cfea, 0x414140de, 0x5dae2223,
+            0xb00361a3, 0x96177a9c, 0xb410ff61, 0xf20015ad,
+        ];
+
+        assert_eq!((result), (expected));
+    }
+}
@@ -0,0 +1,283 @@
+use crate::sha512::compression::{H0, H0_384, H0_512_224, H0_512_256, compress_block};
+use crate::sha512::parsing::parse_block;
+use crate::sha512::to_bytes;
+
+/// Incremental SHA-512 hasher.
+///
+/// # Description
+/// - Holds the eight 64-bit-word chaining value and a 128-byte block buffer.
+/// - `update` can be called any number of times; every full block is
+///   compressed right away, so memory use does not grow with the message.
+/// - `finalize` pads the buffered tail and returns the 512-bit digest.
+///
+/// # Reference
+/// [FIPS PUB 180-4](https://nvlpubs.nist.gov/nistpubs/fips/nist.fips.180-4.pdf)
+#[derive(Clone, Debug)]
+pub struct Sha512 {
+    state: [u64; 8],
+    buffer: [u8; 128],
+    buffer_len: usize,
+    msg_len: u128,
+}
+
+impl Sha512 {
+    /// Create a hasher initialized with the SHA-512 initial hash values.
+    pub fn new() -> Self {
+        Self::with_state(H0)
+    }
+
+    /// Create a hasher starting from the given initial hash value.
+    pub(crate) fn with_state(state: [u64; 8]) -> Self {
+        Sha512 {
+            state,
+            buffer: [0u8; 128],
+            buffer_len: 0,
+            msg_len: 0,
+        }
+    }
+
+    /// Absorb `msg` into the hash state.
+    ///
+    /// # Description
+    /// - Fill the pending buffer first, and compress it once it holds 128 
+    ///   bytes.
+    /// - Compress the remaining full blocks directly from `msg`.
+    /// - Keep the leftover tail (< 128 bytes) in the buffer.
+    pub fn update(&mut self, msg: &[u8]) {
+        self.msg_len = self.msg_len.wrapping_add(msg.len() as u128);
+        let mut i = 0;
+
+        // Top up a partially filled buffer.
+        if self.buffer_len > 0 {
+            while self.buffer_len < 128 && i < msg.len() {
+                self.buffer[self.buffer_len] = msg[i];
+                self.buffer_len += 1;
+                i += 1;
+            }
+            if self.buffer_len < 128 {
+                return;
+            }
+            let block = self.buffer;
+            self.process(&block);
+            self.buffer_len = 0;
+        }
+
+        // Full blocks straight from the input.
+        while msg.len() - i >= 128 {
+            let mut block = [0u8; 128];
+            block.copy_from_slice(&msg[i..i + 128]);
+            self.process(&block);
+            i += 128;
+        }
+
+        // Keep the tail for the next call.
+        while i < msg.len() {
+            self.buffer[self.buffer_len] = msg[i];
+            self.buffer_len += 1;
+            i += 1;
+        }
+    }
+
+    /// Pad the buffered tail and return the 64-byte digest.
+    pub fn finalize(self) -> [u8; 64] {
+        to_bytes(self.finalize_state())
+    }
+
+    /// Pad the buffered tail and return the final hash value as 8 words.
+    ///
+    /// # Description
+    /// - Append a single `1` bit (0x80) after the buffered bytes.
+    /// - If fewer than 16 bytes remain for the length, zero-fill and compress
+    ///   an extra block.
+    /// - Append the message length in bits as a 128-bit big-endian integer.
+    pub(crate) fn finalize_state(mut self) -> [u64; 8] {
+        let msg_as_bits = self.msg_len.wrapping_mul(8);
+        let mut block = [0u8; 128];
+        let mut i = 0;
+        while i < self.buffer_len {
+            block[i] = self.buffer[i];
+            i += 1;
+        }
+
+        // Append 1 + k.
+        block[i] = 0x80;
+        if i >= 112 {
+            self.process(&block);
+            block = [0u8; 128];
+        }
+
+        // Append MSG length, in big endian order.
+        let mut j = 0;
+        while j < 16 {
+            block[112 + j] = (msg_as_bits >> ((15 - j) * 8)) as u8;
+            j += 1;
+        }
+        self.process(&block);
+
+        self.state
+    }
+
+    /// Parse, schedule and compress one 128-byte block into the state.
+    fn process(&mut self, block: &[u8; 128]) {
+        compress_block(&mut self.state, &parse_block(block));
+    }
+}
+
+impl Default for Sha512 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Keep the leftmost `N` bytes of the final hash value.
+fn truncate<const N: usize>(digest: [u64; 8]) -> [u8; N] {
+    let bytes = to_bytes(digest);
+    let mut outp = [0u8; N];
+    let mut i = 0;
+    while i < N {
+        outp[i] = bytes[i];
+        i += 1;
+    }
+
+    outp
+}
+
+/// Incremental SHA-384 hasher (SHA-512 with its own IV, truncated to 384 
+/// bits).
+#[derive(Clone, Debug)]
+pub struct Sha384 {
+    inner: Sha512,
+}
+
+impl Sha384 {
+    /// Create a hasher initialized with the SHA-384 initial hash values.
+    pub fn new() -> Self {
+        Sha384 { inner: Sha512::with_state(H0_384) }
+    }
+
+    /// Absorb `msg` into the hash state.
+    pub fn update(&mut self, msg: &[u8]) {
+        self.inner.update(msg);
+    }
+
+    /// Pad the buffered tail and return the 48-byte digest.
+    pub fn finalize(self) -> [u8; 48] {
+        truncate(self.inner.finalize_state())
+    }
+}
+
+impl Default for Sha384 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Incremental SHA-512/224 hasher (SHA-512 with its own IV, truncated to 224 
+/// bits).
+#[derive(Clone, Debug)]
+pub struct Sha512_224 {
+    inner: Sha512,
+}
+
+impl Sha512_224 {
+    /// Create a hasher initialized with the SHA-512/224 initial hash values.
+    pub fn new() -> Self {
+        Sha512_224 { inner: Sha512::with_state(H0_512_224) }
+    }
+
+    /// Absorb `msg` into the hash state.
+    pub fn update(&mut self, msg: &[u8]) {
+        self.inner.update(msg);
+    }
+
+    /// Pad the buffered tail and return the 28-byte digest.
+    pub fn finalize(self) -> [u8; 28] {
+        truncate(self.inner.finalize_state())
+    }
+}
+
+impl Default for Sha512_224 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Incremental SHA-512/256 hasher (SHA-512 with its own IV, truncated to 256 
+/// bits).
+#[derive(Clone, Debug)]
+pub struct Sha512_256 {
+    inner: Sha512,
+}
+
+impl Sha512_256 {
+    /// Create a hasher initialized with the SHA-512/256 initial hash values.
+    pub fn new() -> Self {
+        Sha512_256 { inner: Sha512::with_state(H0_512_256) }
+    }
+
+    /// Absorb `msg` into the hash state.
+    pub fn update(&mut self, msg: &[u8]) {
+        self.inner.update(msg);
+    }
+
+    /// Pad the buffered tail and return the 32-byte digest.
+    pub fn finalize(self) -> [u8; 32] {
+        truncate(self.inner.finalize_state())
+    }
+}
+
+impl Default for Sha512_256 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::sha512::{compress, pars, padd, sched, sha384, sha512};
+
+    #[test]
+    fn single_update_matches_pipeline() {
+        let msg = b"abc";
+        let mut hasher = Sha512::new();
+        hasher.update(msg);
+
+        let result = hasher.finalize();
+        let expected = to_bytes(compress(sched(pars(padd(msg)))));
+
+        assert_eq!((result), (expected));
+    }
+
+    #[test]
+    fn every_split_of_the_input_matches_one_shot() {
+        let mut msg: Vec<u8> = Vec::new();
+        for i in 0..300u32 {
+            msg.push((i * 13 + 5) as u8);
+        }
+
+        for len in [0, 1, 111, 112, 127, 128, 129, 239, 240, 256, 300] {
+            let expected = sha512(&msg[..len]);
+            for split in 0..=len {
+                let mut hasher = Sha512::new();
+                hasher.update(&msg[..split]);
+                hasher.update(&msg[split..len]);
+
+                assert_eq!((hasher.finalize()), (expected));
+            }
+        }
+    }
+
+    #[test]
+    fn sha384_streaming_matches_one_shot() {
+        let msg = b"a".repeat(1_000);
+        let mut hasher = Sha384::new();
+        hasher.update(&msg[..127]);
+        hasher.update(&msg[127..]);
+
+        let result = hasher.finalize();
+        let expected = sha384(&msg);
+
+        assert_eq!((result), (expected));
+    }
+}
@@ -0,0 +1,15 @@
+pub mod compression;
+pub mod hasher;
+pub mod padding;
+pub mod parsing;
+pub mod schedule;
+pub mod sha;
+pub mod to_bytes;
+
+pub use compression::{compress, compress_block};
+pub use hasher::{Sha384, Sha512, Sha512_224, Sha512_256};
+pub use padding::padd;
+pub use parsing::pars;
+pub use schedule::sched;
+pub use sha::{sha384, sha512, sha512_224, sha512_256};
+pub use to_bytes::to_bytes;
\ No newline at end of file
@@ -0,0 +1,68 @@
+/// Pad a message to a 1024-bit block (SHA-512 padding).
+///
+/// # Arguments
+/// - `msg`: Message as a byte slice (`&[u8]`).  
+///
+/// # Description
+/// Appends padding according to the SHA-512 specification:  
+/// - Append a single `1` bit (0x80).  
+/// - Append `0` bits until the length is congruent to 112 (mod 128).  
+/// - Append the original message length as a 128-bit big-endian integer.  
+///
+/// # Returns
+/// A `Vec<u8>` containing the padded message in bytes.
+pub fn padd(msg: &[u8]) -> Vec<u8> {
+    let mut bytes: Vec<u8> = msg.to_vec();
+
+    // Append 1 + k.
+    bytes.push(0x80);
+    while bytes.len() % 128 != 112 {
+        bytes.push(0x00);
+    }
+
+    let msg_as_bits = (msg.len() as u128) * 8;
+    let mut i = 0;
+    // Append MSG length, in big endian order.
+    while i < 16 {
+        // Shift by (15 - i) * 8 to extract big-endian length bytes (MSB first).
+        let decremental_shift = (15 - i) * 8;
+        let message = ((msg_as_bits >> decremental_shift) & 0xFF) as u8;
+
+        bytes.push(message);
+        i += 1;
+    }
+
+    bytes
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn takes_msg_appends_1_k_and_128_bit_msg_length() {
+        let msg = b"abc";
+
+        let result = padd(msg);
+        let mut expected = vec![0u8; 128];
+        expected[0] = 97;
+        expected[1] = 98;
+        expected[2] = 99;
+        expected[3] = 128;
+        expected[127] = 24;
+
+        assert_eq!((result), (expected));
+    }
+
+    #[test]
+    fn expands_into_second_block_when_length_does_not_fit() {
+        let msg = [0x61u8; 112];
+
+        let result = padd(&msg);
+
+        assert_eq!((result.len()), (256));
+        assert_eq!((result[112]), (0x80));
+        assert_eq!((result[254]), (0x03));
+        assert_eq!((result[255]), (0x80));
+    }
+}
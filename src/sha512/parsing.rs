@@ -0,0 +1,61 @@
+/// Parse a padded message into 1024-bit blocks of 16 × 64-bit words.
+///
+/// # Description
+/// - Groups every 8 bytes into one 64-bit word (big-endian).
+/// - Collects 16 words into a 1024-bit block.
+/// - Expands into multiple blocks if the message length exceeds 1024 bits.
+///
+/// # Returns
+/// A `Vec<[u64; 16]>`, where each element is one 1024-bit block.
+pub fn pars(bytes: Vec<u8>) -> Vec<[u64; 16]> {
+    let mut blocks: Vec<[u64; 16]> = Vec::new();
+    let mut k = 0;
+    // Group every 128 bytes into a 16-word (1024-bit) block.
+    while k + 128 <= bytes.len() {
+        let mut block = [0u8; 128];
+        block.copy_from_slice(&bytes[k..k + 128]);
+        blocks.push(parse_block(&block));
+        // += 128 to work in block range.
+        k += 128;
+    }
+
+    blocks
+}
+
+/// Parse one 128-byte block into 16 × 64-bit big-endian words.
+pub(crate) fn parse_block(bytes: &[u8; 128]) -> [u64; 16] {
+    let mut block = [0u64; 16];
+    let mut l = 0;
+    while l < 16 {
+        let mut word = 0u64;
+        let mut j = 0;
+        // Shift in 8 bytes, most significant first.
+        while j < 8 {
+            word = (word << 8) | (bytes[l * 8 + j] as u64);
+            j += 1;
+        }
+        block[l] = word;
+        l += 1;
+    }
+
+    block
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::sha512::padd;
+
+    #[test]
+    fn pads_message_to_16_64_bit_words() {
+        let bytes = padd(b"abc");
+        let result = pars(bytes);
+
+        let mut expected_block = [0u64; 16];
+        expected_block[0] = 0x6162638000000000;
+        expected_block[15] = 0x0000000000000018;
+        let expected: Vec<[u64; 16]> = vec![expected_block];
+
+        assert_eq!((result), (expected));
+    }
+}
@@ -0,0 +1,60 @@
+use crate::utils::{z64, small_sigma1_64, small_sigma0_64};
+
+/// SHA512 Schedule function.
+///
+/// # Arguments:
+/// Takes blocks as Vec<[u64; 16]>.
+/// Then takes each 1024-bit block outside of the blocks vector.
+///
+/// # Description
+/// - Copies each block into m[0..16].
+/// - Expands words 16..79 using small_sigma0_64 and small_sigma1_64 per 
+///   SHA-512 spec.
+/// - Produces a full 80-word schedule for each block.
+///
+/// # Returns
+/// Scheduled message as vector [u64; 80] for downstream compression.
+pub fn sched(blocks: Vec<[u64; 16]>) -> Vec<[u64; 80]> {
+    let mut schedule: Vec<[u64; 80]> = Vec::new();
+
+    for block in blocks {
+        schedule.push(expand(&block));
+    }
+    schedule
+}
+
+/// Expand a single 1024-bit block into its 80-word message schedule.
+pub(crate) fn expand(block: &[u64; 16]) -> [u64; 80] {
+    let mut m = [0u64; 80];
+    m[..16].copy_from_slice(block);
+
+    for t in 16..80 {
+        m[t] = z64(
+            z64(small_sigma1_64(m[t-2]), m[t-7]), 
+            z64(small_sigma0_64(m[t-15]), m[t-16])
+        );
+    }
+    m
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::sha512::{pars, padd};
+
+    #[test]
+    fn test_schedule_keeps_block_and_expands_to_80_words() {
+        let parsing = pars(padd(b"abc"));
+        let result = sched(parsing.clone());
+
+        assert_eq!((result.len()), (1));
+        assert_eq!((result[0][..16]), (parsing[0]));
+        assert_eq!(
+            (result[0][16]),
+            (z64(
+                z64(small_sigma1_64(result[0][14]), result[0][9]),
+                z64(small_sigma0_64(result[0][1]), result[0][0])
+            ))
+        );
+    }
+}
@@ -0,0 +1,227 @@
+use crate::sha512::{Sha384, Sha512, Sha512_224, Sha512_256};
+
+/// SHA-512: Pads, parses, schedules, and compresses a message into a 512-bit 
+/// hash.
+///
+/// # Arguments
+/// - `msg`: Message as a byte slice (`&[u8]`). 
+///
+/// # Description
+/// - **Padding:** Appends a single '1' bit, then `k` zero bits so that the
+///   total length ≡ 896 (mod 1024). Finally appends the original message
+///   length as a 128-bit big-endian integer.
+/// - **Parsing:** Splits the padded message into 1024-bit blocks 
+///   (16 × 64-bit words).
+/// - **Scheduling:** Expands each block into 80 words using the `σ0` and `σ1` 
+///   functions.
+/// - **Compression:** Iteratively updates the hash state across all blocks
+///   to compute the final digest.
+///
+/// # Returns
+/// A 64-byte array representing the 512-bit hash.
+///
+/// # Notes
+/// SHA-512 supports input messages up to `2^128 − 1` bits.
+/// 
+/// # Reference
+/// [FIPS PUB 180-4](https://nvlpubs.nist.gov/nistpubs/fips/nist.fips.180-4.pdf)
+pub fn sha512(msg: &[u8]) -> [u8; 64] {
+    let mut hasher = Sha512::new();
+    hasher.update(msg);
+    hasher.finalize()
+}
+
+/// SHA-384: SHA-512 with different initial hash values and a 384-bit output.
+///
+/// # Returns
+/// A 48-byte array representing the 384-bit hash.
+pub fn sha384(msg: &[u8]) -> [u8; 48] {
+    let mut hasher = Sha384::new();
+    hasher.update(msg);
+    hasher.finalize()
+}
+
+/// SHA-512/224: SHA-512 with the SHA-512/224 initial hash values and a 
+/// 224-bit output.
+///
+/// # Returns
+/// A 28-byte array representing the 224-bit hash.
+pub fn sha512_224(msg: &[u8]) -> [u8; 28] {
+    let mut hasher = Sha512_224::new();
+    hasher.update(msg);
+    hasher.finalize()
+}
+
+/// SHA-512/256: SHA-512 with the SHA-512/256 initial hash values and a 
+/// 256-bit output.
+///
+/// # Returns
+/// A 32-byte array representing the 256-bit hash.
+pub fn sha512_256(msg: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha512_256::new();
+    hasher.update(msg);
+    hasher.finalize()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn sha512_use_one_word_and_digest() {
+        let result = sha512(b"abc");
+        let expected = [
+            0xdd, 0xaf, 0x35, 0xa1, 0x93, 0x61, 0x7a, 0xba,
+            0xcc, 0x41, 0x73, 0x49, 0xae, 0x20, 0x41, 0x31,
+            0x12, 0xe6, 0xfa, 0x4e, 0x89, 0xa9, 0x7e, 0xa2,
+            0x0a, 0x9e, 0xee, 0xe6, 0x4b, 0x55, 0xd3, 0x9a,
+            0x21, 0x92, 0x99, 0x2a, 0x27, 0x4f, 0xc1, 0xa8,
+            0x36, 0xba, 0x3c, 0x23, 0xa3, 0xfe, 0xeb, 0xbd,
+            0x45, 0x4d, 0x44, 0x23, 0x64, 0x3c, 0xe8, 0x0e,
+            0x2a, 0x9a, 0xc9, 0x4f, 0xa5, 0x4c, 0xa4, 0x9f,
+        ];
+
+        assert_eq!((result), (expected));
+    }
+
+    #[test]
+    fn sha512_expanding_in_second_block_and_digest() {
+        let msg = b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmno\
+        ijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu";
+        let result = sha512(msg);
+        let expected = [
+            0x8e, 0x95, 0x9b, 0x75, 0xda, 0xe3, 0x13, 0xda,
+            0x8c, 0xf4, 0xf7, 0x28, 0x14, 0xfc, 0x14, 0x3f,
+            0x8f, 0x77, 0x79, 0xc6, 0xeb, 0x9f, 0x7f, 0xa1,
+            0x72, 0x99, 0xae, 0xad, 0xb6, 0x88, 0x90, 0x18,
+            0x50, 0x1d, 0x28, 0x9e, 0x49, 0x00, 0xf7, 0xe4,
+            0x33, 0x1b, 0x99, 0xde, 0xc4, 0xb5, 0x43, 0x3a,
+            0xc7, 0xd3, 0x29, 0xee, 0xb6, 0xdd, 0x26, 0x54,
+            0x5e, 0x96, 0xe5, 0x5b, 0x87, 0x4b, 0xe9, 0x09,
+        ];
+
+        assert_eq!((result), (expected));
+    }
+
+    #[test]
+    fn sha512_one_million_a_and_digest() {
+        let msg = b"a".repeat(1_000_000);
+        let result = sha512(&msg);
+        let expected = [
+            0xe7, 0x18, 0x48, 0x3d, 0x0c, 0xe7, 0x69, 0x64,
+            0x4e, 0x2e, 0x42, 0xc7, 0xbc, 0x15, 0xb4, 0x63,
+            0x8e, 0x1f, 0x98, 0xb1, 0x3b, 0x20, 0x44, 0x28,
+            0x56, 0x32, 0xa8, 0x03, 0xaf, 0xa9, 0x73, 0xeb,
+            0xde, 0x0f, 0xf2, 0x44, 0x87, 0x7e, 0xa6, 0x0a,
+            0x4c, 0xb0, 0x43, 0x2c, 0xe5, 0x77, 0xc3, 0x1b,
+            0xeb, 0x00, 0x9c, 0x5c, 0x2c, 0x49, 0xaa, 0x2e,
+            0x4e, 0xad, 0xb2, 0x17, 0xad, 0x8c, 0xc0, 0x9b,
+        ];
+
+        assert_eq!((result), (expected));
+    }
+
+    #[test]
+    fn sha384_use_one_word_and_digest() {
+        let result = sha384(b"abc");
+        let expected = [
+            0xcb, 0x00, 0x75, 0x3f, 0x45, 0xa3, 0x5e, 0x8b,
+            0xb5, 0xa0, 0x3d, 0x69, 0x9a, 0xc6, 0x50, 0x07,
+            0x27, 0x2c, 0x32, 0xab, 0x0e, 0xde, 0xd1, 0x63,
+            0x1a, 0x8b, 0x60, 0x5a, 0x43, 0xff, 0x5b, 0xed,
+            0x80, 0x86, 0x07, 0x2b, 0xa1, 0xe7, 0xcc, 0x23,
+            0x58, 0xba, 0xec, 0xa1, 0x34, 0xc8, 0x25, 0xa7,
+        ];
+
+        assert_eq!((result), (expected));
+    }
+
+    #[test]
+    fn sha384_expanding_in_second_block_and_digest() {
+        let msg = b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmno\
+        ijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu";
+        let result = sha384(msg);
+        let expected = [
+            0x09, 0x33, 0x0c, 0x33, 0xf7, 0x11, 0x47, 0xe8,
+            0x3d, 0x19, 0x2f, 0xc7, 0x82, 0xcd, 0x1b, 0x47,
+            0x53, 0x11, 0x1b, 0x17, 0x3b, 0x3b, 0x05, 0xd2,
+            0x2f, 0xa0, 0x80, 0x86, 0xe3, 0xb0, 0xf7, 0x12,
+            0xfc, 0xc7, 0xc7, 0x1a, 0x55, 0x7e, 0x2d, 0xb9,
+            0x66, 0xc3, 0xe9, 0xfa, 0x91, 0x74, 0x60, 0x39,
+        ];
+
+        assert_eq!((result), (expected));
+    }
+
+    #[test]
+    fn sha384_one_million_a_and_digest() {
+        let msg = b"a".repeat(1_000_000);
+        let result = sha384(&msg);
+        let expected = [
+            0x9d, 0x0e, 0x18, 0x09, 0x71, 0x64, 0x74, 0xcb,
+            0x08, 0x6e, 0x83, 0x4e, 0x31, 0x0a, 0x4a, 0x1c,
+            0xed, 0x14, 0x9e, 0x9c, 0x00, 0xf2, 0x48, 0x52,
+            0x79, 0x72, 0xce, 0xc5, 0x70, 0x4c, 0x2a, 0x5b,
+            0x07, 0xb8, 0xb3, 0xdc, 0x38, 0xec, 0xc4, 0xeb,
+            0xae, 0x97, 0xdd, 0xd8, 0x7f, 0x3d, 0x89, 0x85,
+        ];
+
+        assert_eq!((result), (expected));
+    }
+
+    #[test]
+    fn sha512_224_use_one_word_and_digest() {
+        let result = sha512_224(b"abc");
+        let expected = [
+            0x46, 0x34, 0x27, 0x0f, 0x70, 0x7b, 0x6a, 0x54,
+            0xda, 0xae, 0x75, 0x30, 0x46, 0x08, 0x42, 0xe2,
+            0x0e, 0x37, 0xed, 0x26, 0x5c, 0xee, 0xe9, 0xa4,
+            0x3e, 0x89, 0x24, 0xaa,
+        ];
+
+        assert_eq!((result), (expected));
+    }
+
+    #[test]
+    fn sha512_224_expanding_in_second_block_and_digest() {
+        let msg = b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmno\
+        ijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu";
+        let result = sha512_224(msg);
+        let expected = [
+            0x23, 0xfe, 0xc5, 0xbb, 0x94, 0xd6, 0x0b, 0x23,
+            0x30, 0x81, 0x92, 0x64, 0x0b, 0x0c, 0x45, 0x33,
+            0x35, 0xd6, 0x64, 0x73, 0x4f, 0xe4, 0x0e, 0x72,
+            0x68, 0x67, 0x4a, 0xf9,
+        ];
+
+        assert_eq!((result), (expected));
+    }
+
+    #[test]
+    fn sha512_256_use_one_word_and_digest() {
+        let result = sha512_256(b"abc");
+        let expected = [
+            0x53, 0x04, 0x8e, 0x26, 0x81, 0x94, 0x1e, 0xf9,
+            0x9b, 0x2e, 0x29, 0xb7, 0x6b, 0x4c, 0x7d, 0xab,
+            0xe4, 0xc2, 0xd0, 0xc6, 0x34, 0xfc, 0x6d, 0x46,
+            0xe0, 0xe2, 0xf1, 0x31, 0x07, 0xe7, 0xaf, 0x23,
+        ];
+
+        assert_eq!((result), (expected));
+    }
+
+    #[test]
+    fn sha512_256_expanding_in_second_block_and_digest() {
+        let msg = b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmno\
+        ijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu";
+        let result = sha512_256(msg);
+        let expected = [
+            0x39, 0x28, 0xe1, 0x84, 0xfb, 0x86, 0x90, 0xf8,
+            0x40, 0xda, 0x39, 0x88, 0x12, 0x1d, 0x31, 0xbe,
+            0x65, 0xcb, 0x9d, 0x3e, 0xf8, 0x3e, 0xe6, 0x14,
+            0x6f, 0xea, 0xc8, 0x61, 0xe1, 0x9b, 0x56, 0x3a,
+        ];
+
+        assert_eq!((result), (expected));
+    }
+}
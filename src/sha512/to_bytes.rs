@@ -0,0 +1,44 @@
+/// Convert 8 × 64-bit words (digest state) into a 64-byte array.
+///
+/// Each 64-bit word is split into 8 bytes in big-endian order
+/// (most significant byte first).
+pub fn to_bytes(digest: [u64; 8]) -> [u8; 64] {
+    let mut outp = [0u8; 64];
+    let mut i = 0;
+
+    while i < 8 {
+        let word = digest[i];
+        let mut j = 0;
+
+        while j < 8 {
+            outp[i * 8 + j] = (word >> ((7 - j) * 8)) as u8;
+            j += 1;
+        }
+
+        i += 1;
+    }
+
+    outp
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn converts_mixed_words_correctly() {
+        let digest = [
+            0x0123456789abcdef, 0, 0, 0, 0, 0, 0, 0xdeadbeef00000001,
+        ];
+        let result = to_bytes(digest);
+        let mut expected = [0u8; 64];
+        expected[..8].copy_from_slice(&[
+            0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef,
+        ]);
+        expected[56..].copy_from_slice(&[
+            0xde, 0xad, 0xbe, 0xef, 0x00, 0x00, 0x00, 0x01,
+        ]);
+
+        assert_eq!(result, expected);
+    }
+}
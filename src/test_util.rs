@@ -0,0 +1,31 @@
+//! Fixtures shared by the unit tests.
+
+/// `n` distinct 32-byte leaves; leaf i is filled with the byte `i * 7 + 3`.
+pub(crate) fn leaves(n: usize) -> Vec<[u8; 32]> {
+    let mut out = Vec::new();
+    for i in 0..n {
+        out.push([(i * 7 + 3) as u8; 32]);
+    }
+    out
+}
+
+/// Xorshift64 generator, enough for reproducible random test inputs.
+pub(crate) fn next(state: &mut u64) -> u64 {
+    *state ^= *state << 13;
+    *state ^= *state >> 7;
+    *state ^= *state << 17;
+    *state
+}
+
+/// `n` random 32-byte leaves drawn from `next`.
+pub(crate) fn random_leaves(n: usize, state: &mut u64) -> Vec<[u8; 32]> {
+    let mut out = Vec::with_capacity(n);
+    for _ in 0..n {
+        let mut leaf = [0u8; 32];
+        for chunk in leaf.chunks_mut(8) {
+            chunk.copy_from_slice(&next(state).to_be_bytes());
+        }
+        out.push(leaf);
+    }
+    out
+}
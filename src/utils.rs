@@ -13,6 +13,7 @@ pub fn shr(x: u32, n: u32) -> u32 {
 /// Rotate right (ROTR).
 /// 
 /// Rotate x right by n bits within a 32-bit word (wraps bits around).
+#[allow(clippy::manual_rotate)]
 pub fn rotr(x: u32, n: u32) -> u32 {
     // Normalize 0..31.
     let n = n & 31;
@@ -23,6 +24,7 @@ pub fn rotr(x: u32, n: u32) -> u32 {
 /// Rotate left (ROTL).
 ///
 /// Rotate x left by n bits within a 32-bit word (wraps bits around).
+#[allow(dead_code, clippy::manual_rotate)]
 fn rotl(x: u32, n: u32) -> u32 {
     // Normalize 0..31.
     let n = n & 31;
@@ -94,6 +96,110 @@ pub fn small_sigma1(x: u32) -> u32 {
     rotr(x, 17) ^ rotr(x, 19) ^ shr(x, 10)
 }
 
+/// Modular addition (x + y = (mod 2^64)) on 64-bit words.
+///
+/// Overflow is thrown away by the wrapping add.
+pub fn z64(x: u64, y: u64) -> u64 {
+    x.wrapping_add(y)
+}
+
+/// Logical right shift by n on a 64-bit word (pads with 0s at MSB).
+pub fn shr64(x: u64, n: u32) -> u64 {
+    x >> n
+}
+
+/// Rotate right (ROTR) within a 64-bit word.
+///
+/// Rotate x right by n bits within a 64-bit word (wraps bits around).
+pub fn rotr64(x: u64, n: u32) -> u64 {
+    // Normalize 0..63.
+    let n = n & 63;
+    // Use the complement count within the 64-bit word.
+    (x >> n) | (x << ((64 - n) & 63))
+}
+
+/// Choose on 64-bit words.
+///
+/// If 'x' is 1 then the output bit is 'y'.
+/// If 'x' is 0 then the output bit is 'z'
+pub fn ch64(x: u64, y: u64, z: u64) -> u64 {
+    (x & y) ^ (!(x) & z)
+}
+
+/// Majority on 64-bit words.
+///
+/// If at least 2 of the 3 inputs are 1, output is 1.
+/// Otherwise output is 0.
+pub fn maj64(x: u64, y: u64, z: u64) -> u64 {
+    (x & y) ^ (x & z) ^ (y & z)
+}
+
+/// Big sigma 0 for SHA-384/512 (Upper case).
+///
+/// Follow logical steps:
+/// - Rotate 'x' right by 28 bits.
+/// - Rotate 'x' right by 34 bits.
+/// - Rotate 'x' right by 39 bits.
+/// - Apply XOR bit-by-bit, on all 3 words.
+pub fn big_sigma0_64(x: u64) -> u64 {
+    rotr64(x, 28) ^ rotr64(x, 34) ^ rotr64(x, 39)
+}
+
+/// Big sigma 1 for SHA-384/512 (Upper case).
+///
+/// Follow logical steps:
+/// - Rotate 'x' right by 14 bits.
+/// - Rotate 'x' right by 18 bits.
+/// - Rotate 'x' right by 41 bits.
+/// - Apply XOR bit-by-bit, on all 3 words.
+pub fn big_sigma1_64(x: u64) -> u64 {
+    rotr64(x, 14) ^ rotr64(x, 18) ^ rotr64(x, 41)
+}
+
+/// Small sigma 0 for SHA-384/512 (Lower case).
+///
+/// Follow logical steps:
+/// - Rotate 'x' right by 1 bit.
+/// - Rotate 'x' right by 8 bits.
+/// - Shift 'x' right by 7 bits.
+/// - Apply XOR bit-by-bit, on all 3 words.
+pub fn small_sigma0_64(x: u64) -> u64 {
+    rotr64(x, 1) ^ rotr64(x, 8) ^ shr64(x, 7)
+}
+
+/// Small sigma 1 for SHA-384/512 (Lower case).
+///
+/// Follow logical steps:
+/// - Rotate 'x' right by 19 bits.
+/// - Rotate 'x' right by 61 bits.
+/// - Shift 'x' right by 6 bits.
+/// - Apply XOR bit-by-bit, on all 3 words.
+pub fn small_sigma1_64(x: u64) -> u64 {
+    rotr64(x, 19) ^ rotr64(x, 61) ^ shr64(x, 6)
+}
+
+/// Constant-time equality of two byte slices.
+///
+/// # Description
+/// - Slices of different length are unequal (lengths are not secret).
+/// - Otherwise XOR every byte pair and OR the differences together, so the 
+///   loop never exits early on the first mismatch.
+pub fn ct_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut diff: u8 = 0;
+    let mut i = 0;
+    while i < a.len() {
+        diff |= a[i] ^ b[i];
+        i += 1;
+    }
+
+    // Keep the compiler from turning the fold into an early exit.
+    core::hint::black_box(diff) == 0
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -228,4 +334,97 @@ mod test {
 
         assert_eq!((result), (expected));
     }
-}   
\ No newline at end of file
+
+    #[test]
+    fn computes_addition_modular_2_to_power_of_64() {
+        let x = 0xFFFFFFFF_FFFFFFFF;
+        let y = 0xFFFFFFFF_FFFFFFFF;
+
+        let result = z64(x, y);
+        let expected = 0xFFFFFFFF_FFFFFFFE;
+
+        assert_eq!((result), (expected));
+    }
+
+    #[test]
+    fn rotates_64_bit_word_right_by_n() {
+        let x = 15;
+        let n = 3;
+
+        let result = rotr64(x, n);
+        let expected = 0xE000000000000001;
+
+        assert_eq!((result), (expected));
+    }
+
+    #[test]
+    fn rotate_64_bit_word_by_0_is_identity() {
+        let x = 0x0123456789ABCDEF;
+
+        let result = rotr64(x, 0);
+        let expected = x;
+
+        assert_eq!((result), (expected));
+    }
+
+    #[test]
+    fn ch64_and_maj64_select_bits() {
+        let x = 0xFFFFFFFF_00000000;
+        let y = 0xAAAAAAAA_AAAAAAAA;
+        let z = 0x55555555_55555555;
+
+        assert_eq!((ch64(x, y, z)), (0xAAAAAAAA_55555555));
+        assert_eq!((maj64(x, y, z)), (0xFFFFFFFF_00000000));
+    }
+
+    #[test]
+    fn big_sigma0_64_computes_expected_when_x_is_75() {
+        let x = 75;
+
+        let result = big_sigma0_64(x);
+        let expected = 0x000004a2_56000000;
+
+        assert_eq!((result), (expected));
+    }
+
+    #[test]
+    fn big_sigma1_64_computes_expected_when_x_is_75() {
+        let x = 75;
+
+        let result = big_sigma1_64(x);
+        let expected = 0x013ec000_25800000;
+
+        assert_eq!((result), (expected));
+    }
+
+    #[test]
+    fn small_sigma0_64_computes_expected_when_x_is_75() {
+        let x = 75;
+
+        let result = small_sigma0_64(x);
+        let expected = 0xcb000000_00000025;
+
+        assert_eq!((result), (expected));
+    }
+
+    #[test]
+    fn small_sigma1_64_computes_expected_when_x_is_75() {
+        let x = 75;
+
+        let result = small_sigma1_64(x);
+        let expected = 0x00096000_00000259;
+
+        assert_eq!((result), (expected));
+    }
+
+    #[test]
+    fn ct_eq_compares_whole_slices() {
+        let a = [0x01, 0x02, 0x03, 0x04];
+
+        assert!(ct_eq(&a, &[0x01, 0x02, 0x03, 0x04]));
+        assert!(!ct_eq(&a, &[0x01, 0x02, 0x03, 0x05]));
+        assert!(!ct_eq(&a, &[0x81, 0x02, 0x03, 0x04]));
+        assert!(!ct_eq(&a, &[0x01, 0x02, 0x03]));
+        assert!(ct_eq(&[], &[]));
+    }
+}
\ No newline at end of file